@@ -0,0 +1,181 @@
+package main
+
+import (
+	"fmt"
+	"sync"
+)
+
+// AmbientSound identifies a looped background sound played by the frontend
+type AmbientSound string
+
+const (
+	AmbientWhiteNoise AmbientSound = "white"
+	AmbientPinkNoise  AmbientSound = "pink"
+	AmbientBrownNoise AmbientSound = "brown"
+)
+
+// ambientSounds lists the sounds the frontend knows how to generate
+var ambientSounds = []AmbientSound{AmbientWhiteNoise, AmbientPinkNoise, AmbientBrownNoise}
+
+// AmbientLayer is a single sound in the ambient mix
+type AmbientLayer struct {
+	Sound  AmbientSound `json:"sound"`
+	Volume float64      `json:"volume"` // 0.0 - 1.0
+}
+
+// AmbientStatus represents the current ambient mix for frontend
+type AmbientStatus struct {
+	Playing     bool           `json:"playing"`
+	Layers      []AmbientLayer `json:"layers"`
+	StopOnBreak bool           `json:"stopOnBreak"`
+	FadeOut     bool           `json:"fadeOut"` // set when playback stops because a break started
+}
+
+// AmbientMixer keeps track of which ambient layers should be playing.
+// Playback itself happens in the frontend, which listens for the
+// "ambient-changed" event and mixes the layers with the Web Audio API.
+type AmbientMixer struct {
+	mu          sync.Mutex
+	playing     bool
+	layers      []AmbientLayer
+	stopOnBreak bool
+}
+
+// NewAmbientMixer creates a mixer with a single brown noise layer
+func NewAmbientMixer() *AmbientMixer {
+	return &AmbientMixer{
+		layers:      []AmbientLayer{{Sound: AmbientBrownNoise, Volume: 0.5}},
+		stopOnBreak: true,
+	}
+}
+
+// status returns the current mix (must be called with lock held)
+func (m *AmbientMixer) status() AmbientStatus {
+	layers := make([]AmbientLayer, len(m.layers))
+	copy(layers, m.layers)
+
+	return AmbientStatus{
+		Playing:     m.playing,
+		Layers:      layers,
+		StopOnBreak: m.stopOnBreak,
+	}
+}
+
+// validateAmbientMix checks that every layer uses a known sound once with a sane volume
+func validateAmbientMix(layers []AmbientLayer) ValidationErrors {
+	var errs ValidationErrors
+	seen := make(map[AmbientSound]bool)
+	for i, layer := range layers {
+		field := fmt.Sprintf("layers[%d]", i)
+		known := false
+		for _, sound := range ambientSounds {
+			if layer.Sound == sound {
+				known = true
+				break
+			}
+		}
+		if !known {
+			errs = append(errs, FieldError{Field: field + ".sound", Message: fmt.Sprintf("unknown ambient sound %q", layer.Sound)})
+		} else if seen[layer.Sound] {
+			errs = append(errs, FieldError{Field: field + ".sound", Message: fmt.Sprintf("ambient sound %q appears more than once", layer.Sound)})
+		}
+		if layer.Volume < 0 || layer.Volume > 1 {
+			errs = append(errs, FieldError{Field: field + ".volume", Message: "must be between 0.0 and 1.0"})
+		}
+		seen[layer.Sound] = true
+	}
+	return errs
+}
+
+// StartAmbient starts playing the current ambient mix
+func (a *App) StartAmbient() (AmbientStatus, error) {
+	if err := a.checkWritable(); err != nil {
+		return a.GetAmbientStatus(), err
+	}
+
+	a.ambient.mu.Lock()
+	a.ambient.playing = true
+	status := a.ambient.status()
+	a.ambient.mu.Unlock()
+
+	a.emit("ambient-changed", status)
+	return status, nil
+}
+
+// StopAmbient stops all ambient layers
+func (a *App) StopAmbient() (AmbientStatus, error) {
+	if err := a.checkWritable(); err != nil {
+		return a.GetAmbientStatus(), err
+	}
+
+	return a.stopAmbient(), nil
+}
+
+// stopAmbient stops all ambient layers without checking presentation mode
+func (a *App) stopAmbient() AmbientStatus {
+	a.ambient.mu.Lock()
+	a.ambient.playing = false
+	status := a.ambient.status()
+	a.ambient.mu.Unlock()
+
+	a.emit("ambient-changed", status)
+	return status
+}
+
+// SetAmbientMix replaces the ambient layers and their volumes
+func (a *App) SetAmbientMix(layers []AmbientLayer) (AmbientStatus, error) {
+	if err := a.checkWritable(); err != nil {
+		return a.GetAmbientStatus(), err
+	}
+
+	if errs := validateAmbientMix(layers); len(errs) > 0 {
+		return a.GetAmbientStatus(), errs
+	}
+
+	a.ambient.mu.Lock()
+	a.ambient.layers = append([]AmbientLayer(nil), layers...)
+	status := a.ambient.status()
+	a.ambient.mu.Unlock()
+
+	a.persistSettings()
+	a.emit("ambient-changed", status)
+	return status, nil
+}
+
+// SetAmbientStopOnBreak configures whether ambient sound stops when a break starts
+func (a *App) SetAmbientStopOnBreak(stop bool) (AmbientStatus, error) {
+	if err := a.checkWritable(); err != nil {
+		return a.GetAmbientStatus(), err
+	}
+
+	a.ambient.mu.Lock()
+	a.ambient.stopOnBreak = stop
+	status := a.ambient.status()
+	a.ambient.mu.Unlock()
+
+	a.persistSettings()
+	a.emit("ambient-changed", status)
+	return status, nil
+}
+
+// GetAmbientStatus returns the current ambient mix
+func (a *App) GetAmbientStatus() AmbientStatus {
+	a.ambient.mu.Lock()
+	defer a.ambient.mu.Unlock()
+	return a.ambient.status()
+}
+
+// stopAmbientForBreak stops ambient playback if configured to do so on breaks
+func (a *App) stopAmbientForBreak() {
+	a.ambient.mu.Lock()
+	if !a.ambient.playing || !a.ambient.stopOnBreak {
+		a.ambient.mu.Unlock()
+		return
+	}
+	a.ambient.playing = false
+	status := a.ambient.status()
+	a.ambient.mu.Unlock()
+
+	status.FadeOut = true
+	a.emit("ambient-changed", status)
+}
@@ -3,13 +3,34 @@ package main
 import (
 	"context"
 	"sync"
+	"sync/atomic"
 	"time"
+
+	"github.com/wailsapp/wails/v2/pkg/runtime"
 )
 
 // App struct
 type App struct {
-	ctx   context.Context
-	timer *PomodoroTimer
+	ctx          context.Context
+	timer        *PomodoroTimer
+	ambient      *AmbientMixer
+	sounds       *SoundSettings
+	schedule     *WorkingSchedule
+	overlay      *BreakOverlay
+	window       *WindowPreferences
+	presentation *PresentationMode
+	focusLock    *FocusLock
+	shortcuts    *ShortcutSettings
+
+	storeMu sync.RWMutex // guards store and profile, which change on profile switch
+	store   *settingsStore
+	profile string
+
+	configDir string // directory holding settings.json and profiles
+	startedAt time.Time
+
+	launchMinimized bool        // started with --minimized or the start-minimized setting
+	quitting        atomic.Bool // the OS asked the app to quit
 }
 
 // TimerState represents the current state of the timer
@@ -38,6 +59,13 @@ type PomodoroTimer struct {
 	shortBreakDuration int // minutes
 	longBreakDuration  int // minutes
 	longBreakInterval  int // pomodoros before long break
+
+	// onTransition is called after an automatic phase change with the state the
+	// timer ended up in, which is StateIdle if shouldContinue stopped it. The
+	// lock is released first, as the callback may touch the window.
+	onTransition func(from, to TimerState)
+	// shouldContinue decides whether the next phase starts automatically, with the lock held
+	shouldContinue func(next TimerState) bool
 }
 
 // TimerStatus represents the current timer status for frontend
@@ -50,9 +78,21 @@ type TimerStatus struct {
 
 // NewApp creates a new App application struct
 func NewApp() *App {
-	return &App{
-		timer: NewPomodoroTimer(),
+	app := &App{
+		timer:        NewPomodoroTimer(),
+		ambient:      NewAmbientMixer(),
+		sounds:       NewSoundSettings(),
+		schedule:     NewWorkingSchedule(),
+		overlay:      NewBreakOverlay(),
+		window:       NewWindowPreferences(),
+		presentation: NewPresentationMode(),
+		focusLock:    NewFocusLock(),
+		shortcuts:    NewShortcutSettings(),
 	}
+	app.timer.onTransition = app.handleTransition
+	app.timer.shouldContinue = app.allowAutoContinue
+
+	return app
 }
 
 // NewPomodoroTimer creates a new pomodoro timer with default settings
@@ -76,10 +116,69 @@ func NewPomodoroTimer() *PomodoroTimer {
 // so we can call the runtime methods
 func (a *App) startup(ctx context.Context) {
 	a.ctx = ctx
+	a.startedAt = time.Now()
+	a.restoreTimer()
+	go a.watchWorkday()
+	go a.quitOnSignal()
+}
+
+// beforeClose is called when the window is closing. Closing is
+// prevented while a locked focus session is running, unless the OS
+// asked the app to quit.
+func (a *App) beforeClose(ctx context.Context) bool {
+	if a.quitting.Load() {
+		return false
+	}
+	return a.checkUnlocked("quit") != nil
+}
+
+// domReady is called once the frontend has loaded and can receive events
+func (a *App) domReady(ctx context.Context) {
+	a.reportSettingsDefaults()
+	if a.launchMinimized {
+		a.emit("started-in-background")
+	}
+}
+
+// emit sends an event to the frontend once the runtime is available
+func (a *App) emit(eventName string, data ...interface{}) {
+	if a.ctx == nil {
+		return
+	}
+	runtime.EventsEmit(a.ctx, eventName, data...)
+}
+
+// handleTransition reacts to automatic phase changes of the timer.
+// It runs after the timer lock has been released.
+func (a *App) handleTransition(from, to TimerState) {
+	a.announceSessionComplete(from, to)
+	if to == StateIdle {
+		a.emit("working-hours-ended")
+	}
+
+	// Stop or Pause may have changed the state since the timer lock was released
+	if a.GetTimerStatus().State != to {
+		return
+	}
+
+	if to == StateBreak || to == StateLongBreak {
+		a.stopAmbientForBreak()
+	}
+	a.updateBreakOverlay(to)
+
+	if to == StateWorking {
+		a.focusLock.engage()
+	} else {
+		a.focusLock.release()
+	}
 }
 
 // StartTimer starts a new pomodoro session
-func (a *App) StartTimer() TimerStatus {
+func (a *App) StartTimer() (TimerStatus, error) {
+	if err := a.checkWritable(); err != nil {
+		return a.GetTimerStatus(), err
+	}
+
 	a.timer.mu.Lock()
 	defer a.timer.mu.Unlock()
 	
@@ -87,16 +186,25 @@ func (a *App) StartTimer() TimerStatus {
 		a.timer.state = StateWorking
 		a.timer.remainingTime = a.timer.workDuration * 60 // convert to seconds
 		a.timer.startTicking()
+		a.focusLock.engage()
 	} else if a.timer.state == StatePaused {
 		a.timer.state = StateWorking
 		a.timer.startTicking()
+		a.focusLock.engage()
 	}
 	
-	return a.getStatus()
+	return a.getStatus(), nil
 }
 
 // PauseTimer pauses the current session
-func (a *App) PauseTimer() TimerStatus {
+func (a *App) PauseTimer() (TimerStatus, error) {
+	if err := a.checkWritable(); err != nil {
+		return a.GetTimerStatus(), err
+	}
+	if err := a.checkUnlocked("pause"); err != nil {
+		return a.GetTimerStatus(), err
+	}
+
 	a.timer.mu.Lock()
 	defer a.timer.mu.Unlock()
 	
@@ -105,20 +213,31 @@ func (a *App) PauseTimer() TimerStatus {
 		a.timer.stopTicking()
 	}
 	
-	return a.getStatus()
+	return a.getStatus(), nil
 }
 
 // StopTimer stops and resets the timer
-func (a *App) StopTimer() TimerStatus {
+func (a *App) StopTimer() (TimerStatus, error) {
+	if err := a.checkWritable(); err != nil {
+		return a.GetTimerStatus(), err
+	}
+	if err := a.checkUnlocked("stop"); err != nil {
+		return a.GetTimerStatus(), err
+	}
+
 	a.timer.mu.Lock()
-	defer a.timer.mu.Unlock()
-	
 	a.timer.state = StateIdle
 	a.timer.remainingTime = 0
 	a.timer.currentCycle = 1
 	a.timer.stopTicking()
-	
-	return a.getStatus()
+	status := a.getStatus()
+	a.timer.mu.Unlock()
+
+	// Window changes wait on the UI thread, so they run without the timer lock
+	a.hideBreakOverlay()
+	a.focusLock.release()
+
+	return status, nil
 }
 
 // GetTimerStatus returns the current timer status
@@ -154,7 +273,15 @@ func (t *PomodoroTimer) startTicking() {
 				t.remainingTime--
 				
 				if t.remainingTime <= 0 {
-					t.handleTimerComplete()
+					// handleTimerComplete either stops the ticker or starts a
+					// new goroutine for the next phase, so this one ends here
+					from, to := t.handleTimerComplete()
+					t.mu.Unlock()
+
+					if t.onTransition != nil {
+						t.onTransition(from, to)
+					}
+					return
 				}
 				t.mu.Unlock()
 				
@@ -178,8 +305,12 @@ func (t *PomodoroTimer) stopTicking() {
 	}
 }
 
-// handleTimerComplete handles timer completion and state transitions
-func (t *PomodoroTimer) handleTimerComplete() {
+// handleTimerComplete handles timer completion and state transitions and
+// returns the phase that finished and the state the timer is now in
+// (must be called with lock held)
+func (t *PomodoroTimer) handleTimerComplete() (TimerState, TimerState) {
+	previous := t.state
+
 	switch t.state {
 	case StateWorking:
 		t.completedPomodoros++
@@ -199,19 +330,40 @@ func (t *PomodoroTimer) handleTimerComplete() {
 		t.remainingTime = t.workDuration * 60
 	}
 	
-	// Continue ticking for the next phase
-	t.startTicking()
+	if t.shouldContinue != nil && !t.shouldContinue(t.state) {
+		t.state = StateIdle
+		t.remainingTime = 0
+		t.currentCycle = 1
+		t.ticker.Stop()
+		t.ticker = nil
+	} else {
+		// Continue ticking for the next phase
+		t.startTicking()
+	}
+
+	return previous, t.state
 }
 
-// UpdateSettings updates timer settings
-func (a *App) UpdateSettings(workDuration, shortBreak, longBreak, longBreakInterval int) {
+// UpdateSettings updates timer settings.
+// Nothing is changed if any value is out of range.
+func (a *App) UpdateSettings(workDuration, shortBreak, longBreak, longBreakInterval int) error {
+	if err := a.checkWritable(); err != nil {
+		return err
+	}
+
+	if errs := validateTimerSettings(workDuration, shortBreak, longBreak, longBreakInterval); len(errs) > 0 {
+		return errs
+	}
+
 	a.timer.mu.Lock()
-	defer a.timer.mu.Unlock()
-	
 	a.timer.workDuration = workDuration
 	a.timer.shortBreakDuration = shortBreak
 	a.timer.longBreakDuration = longBreak
 	a.timer.longBreakInterval = longBreakInterval
+	a.timer.mu.Unlock()
+
+	a.persistSettings()
+	return nil
 }
 
 // GetSettings returns current timer settings
@@ -0,0 +1,155 @@
+package main
+
+import (
+	"crypto/rand"
+	"crypto/sha256"
+	"crypto/subtle"
+	"encoding/hex"
+	"errors"
+	"strings"
+	"sync"
+)
+
+// focusLockPhrase unlocks a focus session when no passcode is set
+const focusLockPhrase = "I want to stop focusing"
+
+// errFocusLocked is returned by commands blocked during a locked focus session
+var errFocusLocked = errors.New("focus lock is on; unlock the session first")
+
+// FocusLockSettings is the persisted focus lock configuration.
+// Only a salted hash of the passcode is stored.
+type FocusLockSettings struct {
+	Enabled      bool   `json:"enabled"`
+	PasscodeSalt string `json:"passcodeSalt"`
+	PasscodeHash string `json:"passcodeHash"`
+}
+
+// FocusLockStatus represents the focus lock state for frontend
+type FocusLockStatus struct {
+	Enabled     bool   `json:"enabled"`
+	HasPasscode bool   `json:"hasPasscode"`
+	Locked      bool   `json:"locked"`
+	Phrase      string `json:"phrase"` // typed instead of a passcode when none is set
+}
+
+// FocusLock blocks stopping, pausing and quitting during work sessions
+type FocusLock struct {
+	mu       sync.Mutex
+	settings FocusLockSettings
+	locked   bool // a work session is running and has not been unlocked
+}
+
+// NewFocusLock creates a disabled focus lock
+func NewFocusLock() *FocusLock {
+	return &FocusLock{}
+}
+
+// hashPasscode returns the hex SHA-256 of salt and passcode
+func hashPasscode(salt, passcode string) string {
+	sum := sha256.Sum256([]byte(salt + passcode))
+	return hex.EncodeToString(sum[:])
+}
+
+// status returns the lock state (must be called with lock held)
+func (l *FocusLock) status() FocusLockStatus {
+	return FocusLockStatus{
+		Enabled:     l.settings.Enabled,
+		HasPasscode: l.settings.PasscodeHash != "",
+		Locked:      l.locked,
+		Phrase:      focusLockPhrase,
+	}
+}
+
+// engage locks the current work session if the focus lock is enabled
+func (l *FocusLock) engage() {
+	l.mu.Lock()
+	defer l.mu.Unlock()
+	l.locked = l.settings.Enabled
+}
+
+// release ends the lock because the work session is over
+func (l *FocusLock) release() {
+	l.mu.Lock()
+	defer l.mu.Unlock()
+	l.locked = false
+}
+
+// isLocked reports whether the current work session is locked
+func (l *FocusLock) isLocked() bool {
+	l.mu.Lock()
+	defer l.mu.Unlock()
+	return l.locked
+}
+
+// checkUnlocked rejects an action during a locked focus session and tells
+// the frontend which action was blocked so it can ask for the passcode.
+// Only a running work session can be locked; a lock left over from a
+// session that has ended is released instead.
+func (a *App) checkUnlocked(action string) error {
+	if !a.focusLock.isLocked() {
+		return nil
+	}
+	if a.GetTimerStatus().State != StateWorking {
+		a.focusLock.release()
+		return nil
+	}
+	a.emit("focus-lock-blocked", action)
+	return errFocusLocked
+}
+
+// GetFocusLockStatus returns whether the focus lock is enabled and engaged
+func (a *App) GetFocusLockStatus() FocusLockStatus {
+	a.focusLock.mu.Lock()
+	defer a.focusLock.mu.Unlock()
+	return a.focusLock.status()
+}
+
+// SetFocusLock enables or disables the focus lock. An empty passcode
+// means the confirmation phrase is typed instead. The lock cannot be
+// changed while it is engaged.
+func (a *App) SetFocusLock(enabled bool, passcode string) (FocusLockStatus, error) {
+	if err := a.checkWritable(); err != nil {
+		return a.GetFocusLockStatus(), err
+	}
+	if err := a.checkUnlocked("settings"); err != nil {
+		return a.GetFocusLockStatus(), err
+	}
+
+	settings := FocusLockSettings{Enabled: enabled}
+	if passcode != "" {
+		salt := make([]byte, 16)
+		if _, err := rand.Read(salt); err != nil {
+			return a.GetFocusLockStatus(), err
+		}
+		settings.PasscodeSalt = hex.EncodeToString(salt)
+		settings.PasscodeHash = hashPasscode(settings.PasscodeSalt, passcode)
+	}
+
+	a.focusLock.mu.Lock()
+	a.focusLock.settings = settings
+	status := a.focusLock.status()
+	a.focusLock.mu.Unlock()
+
+	a.persistSettings()
+	return status, nil
+}
+
+// UnlockFocus releases the lock on the current work session with the
+// passcode, or with the confirmation phrase when no passcode is set
+func (a *App) UnlockFocus(code string) (FocusLockStatus, error) {
+	a.focusLock.mu.Lock()
+	defer a.focusLock.mu.Unlock()
+
+	settings := a.focusLock.settings
+	if settings.PasscodeHash != "" {
+		hash := hashPasscode(settings.PasscodeSalt, code)
+		if subtle.ConstantTimeCompare([]byte(hash), []byte(settings.PasscodeHash)) != 1 {
+			return a.focusLock.status(), errors.New("incorrect passcode")
+		}
+	} else if !strings.EqualFold(strings.TrimSpace(code), focusLockPhrase) {
+		return a.focusLock.status(), errors.New("type the confirmation phrase exactly to unlock")
+	}
+
+	a.focusLock.locked = false
+	return a.focusLock.status(), nil
+}
@@ -0,0 +1,104 @@
+import { getAlarmContext } from './context'
+
+// Gentle alarm: rings every few seconds, reaching full volume after a few rings
+const GENTLE_INTERVAL_MS = 4000
+const GENTLE_STEPS = 6
+const GENTLE_MAX_RINGS = 20
+
+export interface SessionSound {
+  sound: string
+  volume: number
+}
+
+export interface SessionCompleteEvent {
+  finished: string
+  next: string
+  sound: SessionSound
+  device: string
+  gentle: boolean
+  silenced: boolean
+}
+
+let cancelGentle: (() => void) | null = null
+
+// Play a single enveloped tone starting at the given offset
+const tone = (ctx: AudioContext, output: AudioNode, type: OscillatorType, frequency: number, start: number, duration: number) => {
+  const osc = ctx.createOscillator()
+  const env = ctx.createGain()
+  const at = ctx.currentTime + start
+
+  osc.type = type
+  osc.frequency.value = frequency
+  env.gain.setValueAtTime(0, at)
+  env.gain.linearRampToValueAtTime(1, at + 0.01)
+  env.gain.exponentialRampToValueAtTime(0.001, at + duration)
+
+  osc.connect(env)
+  env.connect(output)
+  osc.start(at)
+  osc.stop(at + duration)
+}
+
+// Play a completion sound at the given volume on the given output device
+export const playAlarm = async ({ sound, volume }: SessionSound, device = '') => {
+  if (sound === 'none' || volume <= 0) {
+    return
+  }
+
+  const ctx = await getAlarmContext(device)
+  const output = ctx.createGain()
+  output.gain.value = volume
+  output.connect(ctx.destination)
+
+  switch (sound) {
+    case 'chime':
+      tone(ctx, output, 'sine', 659.25, 0, 0.8)
+      tone(ctx, output, 'sine', 783.99, 0.25, 0.8)
+      tone(ctx, output, 'sine', 1046.5, 0.5, 1.2)
+      break
+    case 'digital':
+      for (let i = 0; i < 4; i++) {
+        tone(ctx, output, 'square', 1000, i * 0.25, 0.12)
+      }
+      break
+    default:
+      tone(ctx, output, 'sine', 880, 0, 2)
+      tone(ctx, output, 'sine', 1760, 0, 1)
+  }
+}
+
+// Stop a gentle alarm that is still ringing
+export const stopAlarm = () => {
+  if (cancelGentle) {
+    cancelGentle()
+    cancelGentle = null
+  }
+}
+
+// Ring quietly and get louder until the user interacts with the window
+export const playGentleAlarm = (sound: SessionSound, device = '') => {
+  stopAlarm()
+
+  let rings = 0
+  const ring = () => {
+    rings++
+    if (rings > GENTLE_MAX_RINGS) {
+      stopAlarm()
+      return
+    }
+    const volume = sound.volume * Math.min(rings / GENTLE_STEPS, 1)
+    playAlarm({ sound: sound.sound, volume }, device)
+  }
+
+  const interval = setInterval(ring, GENTLE_INTERVAL_MS)
+  window.addEventListener('pointerdown', stopAlarm)
+  window.addEventListener('keydown', stopAlarm)
+
+  cancelGentle = () => {
+    clearInterval(interval)
+    window.removeEventListener('pointerdown', stopAlarm)
+    window.removeEventListener('keydown', stopAlarm)
+  }
+
+  ring()
+}
@@ -0,0 +1,120 @@
+import { main } from '../../wailsjs/go/models'
+import { getContext } from './context'
+
+// Seconds of generated noise per looped buffer
+const BUFFER_SECONDS = 4
+// Seconds to fade out when a break stops ambient playback
+const FADE_OUT_SECONDS = 5
+
+interface PlayingLayer {
+  source: AudioBufferSourceNode
+  gain: GainNode
+}
+
+const buffers = new Map<string, AudioBuffer>()
+const playing = new Map<string, PlayingLayer>()
+
+// Fill a buffer with white, pink or brown noise
+const createNoiseBuffer = (ctx: AudioContext, sound: string): AudioBuffer => {
+  const length = ctx.sampleRate * BUFFER_SECONDS
+  const buffer = ctx.createBuffer(1, length, ctx.sampleRate)
+  const data = buffer.getChannelData(0)
+
+  // Pink noise filter state (Paul Kellet's economy method)
+  let b0 = 0, b1 = 0, b2 = 0
+  // Brown noise integrator state
+  let last = 0
+
+  for (let i = 0; i < length; i++) {
+    const white = Math.random() * 2 - 1
+    switch (sound) {
+      case 'pink':
+        b0 = 0.99765 * b0 + white * 0.0990460
+        b1 = 0.96300 * b1 + white * 0.2965164
+        b2 = 0.57000 * b2 + white * 1.0526913
+        data[i] = (b0 + b1 + b2 + white * 0.1848) * 0.25
+        break
+      case 'brown':
+        last = (last + 0.02 * white) / 1.02
+        data[i] = last * 3.5
+        break
+      default:
+        data[i] = white * 0.5
+    }
+  }
+
+  return buffer
+}
+
+const getBuffer = (ctx: AudioContext, sound: string): AudioBuffer => {
+  let buffer = buffers.get(sound)
+  if (!buffer) {
+    buffer = createNoiseBuffer(ctx, sound)
+    buffers.set(sound, buffer)
+  }
+  return buffer
+}
+
+const stopLayer = (sound: string, fadeSeconds = 0) => {
+  const layer = playing.get(sound)
+  if (!layer) {
+    return
+  }
+  playing.delete(sound)
+
+  const now = layer.gain.context.currentTime
+  layer.source.onended = () => {
+    layer.source.disconnect()
+    layer.gain.disconnect()
+  }
+
+  if (fadeSeconds > 0) {
+    layer.gain.gain.setTargetAtTime(0, now, fadeSeconds / 4)
+    layer.source.stop(now + fadeSeconds)
+  } else {
+    layer.source.stop()
+  }
+}
+
+// Bring playback in line with the ambient status reported by the backend
+export const applyAmbient = (status: main.AmbientStatus) => {
+  const wanted = new Map<string, number>()
+  if (status.playing) {
+    for (const layer of status.layers || []) {
+      wanted.set(layer.sound, layer.volume)
+    }
+  }
+
+  const fadeSeconds = status.fadeOut ? FADE_OUT_SECONDS : 0
+  for (const sound of Array.from(playing.keys())) {
+    if (!wanted.has(sound)) {
+      stopLayer(sound, fadeSeconds)
+    }
+  }
+
+  if (wanted.size === 0) {
+    return
+  }
+
+  const ctx = getContext()
+
+  wanted.forEach((volume, sound) => {
+    const existing = playing.get(sound)
+    if (existing) {
+      existing.gain.gain.setTargetAtTime(volume, ctx.currentTime, 0.1)
+      return
+    }
+
+    const gain = ctx.createGain()
+    gain.gain.value = volume
+    gain.connect(ctx.destination)
+
+    const source = ctx.createBufferSource()
+    source.buffer = getBuffer(ctx, sound)
+    source.loop = true
+    source.connect(gain)
+    source.start()
+
+    playing.set(sound, { source, gain })
+  })
+}
@@ -0,0 +1,43 @@
+// AudioContext.setSinkId is not in the bundled DOM typings yet
+type SinkableContext = AudioContext & { setSinkId?: (sinkId: string) => Promise<void> }
+
+let context: AudioContext | null = null
+let alarmContext: SinkableContext | null = null
+let alarmSink = ''
+
+// Shared audio context for ambient sounds
+export const getContext = (): AudioContext => {
+  if (!context) {
+    context = new AudioContext()
+  }
+  if (context.state === 'suspended') {
+    context.resume()
+  }
+  return context
+}
+
+// Audio context for alarms, routed to the selected output device when supported.
+// Falls back to the default output if the device has disappeared.
+export const getAlarmContext = async (deviceId: string): Promise<AudioContext> => {
+  if (!alarmContext) {
+    alarmContext = new AudioContext() as SinkableContext
+  }
+  if (alarmContext.state === 'suspended') {
+    alarmContext.resume()
+  }
+
+  if (alarmContext.setSinkId && deviceId !== alarmSink) {
+    try {
+      await alarmContext.setSinkId(deviceId)
+      alarmSink = deviceId
+    } catch (error) {
+      console.warn(`Output device ${deviceId} unavailable, using default:`, error)
+      if (alarmSink !== '') {
+        await alarmContext.setSinkId('')
+        alarmSink = ''
+      }
+    }
+  }
+
+  return alarmContext
+}
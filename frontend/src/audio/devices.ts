@@ -0,0 +1,19 @@
+export interface OutputDevice {
+  id: string
+  label: string
+}
+
+// List the audio output devices available to the webview
+export const listOutputDevices = async (): Promise<OutputDevice[]> => {
+  if (!navigator.mediaDevices || !navigator.mediaDevices.enumerateDevices) {
+    return []
+  }
+
+  const devices = await navigator.mediaDevices.enumerateDevices()
+  return devices
+    .filter((device) => device.kind === 'audiooutput')
+    .map((device, index) => ({
+      id: device.deviceId === 'default' ? '' : device.deviceId,
+      label: device.label || `Output ${index + 1}`
+    }))
+}
@@ -2,14 +2,88 @@
 // This file is automatically generated. DO NOT EDIT
 import {main} from '../models';
 
+export function CreateProfile(arg1:string):Promise<main.ProfileList>;
+
+export function GetAlarmOutputDevice():Promise<string>;
+
+export function GetAllSettings():Promise<main.Settings>;
+
+export function GetAmbientStatus():Promise<main.AmbientStatus>;
+
+export function GetBreakOverlay():Promise<boolean>;
+
+export function GetFocusLockStatus():Promise<main.FocusLockStatus>;
+
+export function GetGentleAlarm():Promise<boolean>;
+
+export function GetHealthMetrics():Promise<main.HealthMetrics>;
+
+export function GetLaunchedMinimized():Promise<boolean>;
+
+export function GetMicrophoneStatus():Promise<main.MicrophoneStatus>;
+
+export function GetPresentationMode():Promise<boolean>;
+
 export function GetSettings():Promise<Record<string, number>>;
 
+export function GetSettingsLoadReport():Promise<main.SettingsLoadReport>;
+
+export function GetShortcuts():Promise<Record<string, string>>;
+
+export function GetSoundSettings():Promise<Record<string, main.SessionSound>>;
+
+export function GetStartMinimized():Promise<boolean>;
+
 export function GetTimerStatus():Promise<main.TimerStatus>;
 
+export function GetWorkingHours():Promise<main.WorkingHours>;
+
+export function HideBreakOverlay():Promise<void>;
+
+export function IsWithinWorkingHours():Promise<boolean>;
+
+export function ListProfiles():Promise<main.ProfileList>;
+
 export function PauseTimer():Promise<main.TimerStatus>;
 
+export function SetAlarmOutputDevice(arg1:string):Promise<void>;
+
+export function SetAmbientMix(arg1:Array<main.AmbientLayer>):Promise<main.AmbientStatus>;
+
+export function SetAmbientStopOnBreak(arg1:boolean):Promise<main.AmbientStatus>;
+
+export function SetBreakOverlay(arg1:boolean):Promise<void>;
+
+export function SetFocusLock(arg1:boolean,arg2:string):Promise<main.FocusLockStatus>;
+
+export function SetGentleAlarm(arg1:boolean):Promise<void>;
+
+export function SetMuteAlarmsWhenMicActive(arg1:boolean):Promise<void>;
+
+export function SetPresentationMode(arg1:boolean):Promise<void>;
+
+export function SetShortcut(arg1:string,arg2:string):Promise<Record<string, string>>;
+
+export function SetStartMinimized(arg1:boolean):Promise<void>;
+
+export function SetWorkingHours(arg1:main.WorkingHours):Promise<void>;
+
+export function ShowBreakOverlay():Promise<void>;
+
+export function SkipBreak():Promise<main.TimerStatus>;
+
+export function StartAmbient():Promise<main.AmbientStatus>;
+
 export function StartTimer():Promise<main.TimerStatus>;
 
+export function StopAmbient():Promise<main.AmbientStatus>;
+
 export function StopTimer():Promise<main.TimerStatus>;
 
+export function SwitchProfile(arg1:string):Promise<main.ProfileList>;
+
+export function UnlockFocus(arg1:string):Promise<main.FocusLockStatus>;
+
+export function UpdateSessionSound(arg1:string,arg2:main.SessionSound):Promise<void>;
+
 export function UpdateSettings(arg1:number,arg2:number,arg3:number,arg4:number):Promise<void>;
@@ -2,26 +2,174 @@
 // Cynhyrchwyd y ffeil hon yn awtomatig. PEIDIWCH Â MODIWL
 // This file is automatically generated. DO NOT EDIT
 
+export function CreateProfile(arg1) {
+  return window['go']['main']['App']['CreateProfile'](arg1);
+}
+
+export function GetAlarmOutputDevice() {
+  return window['go']['main']['App']['GetAlarmOutputDevice']();
+}
+
+export function GetAllSettings() {
+  return window['go']['main']['App']['GetAllSettings']();
+}
+
+export function GetAmbientStatus() {
+  return window['go']['main']['App']['GetAmbientStatus']();
+}
+
+export function GetBreakOverlay() {
+  return window['go']['main']['App']['GetBreakOverlay']();
+}
+
+export function GetFocusLockStatus() {
+  return window['go']['main']['App']['GetFocusLockStatus']();
+}
+
+export function GetGentleAlarm() {
+  return window['go']['main']['App']['GetGentleAlarm']();
+}
+
+export function GetHealthMetrics() {
+  return window['go']['main']['App']['GetHealthMetrics']();
+}
+
+export function GetLaunchedMinimized() {
+  return window['go']['main']['App']['GetLaunchedMinimized']();
+}
+
+export function GetMicrophoneStatus() {
+  return window['go']['main']['App']['GetMicrophoneStatus']();
+}
+
+export function GetPresentationMode() {
+  return window['go']['main']['App']['GetPresentationMode']();
+}
+
 export function GetSettings() {
   return window['go']['main']['App']['GetSettings']();
 }
 
+export function GetSettingsLoadReport() {
+  return window['go']['main']['App']['GetSettingsLoadReport']();
+}
+
+export function GetShortcuts() {
+  return window['go']['main']['App']['GetShortcuts']();
+}
+
+export function GetSoundSettings() {
+  return window['go']['main']['App']['GetSoundSettings']();
+}
+
+export function GetStartMinimized() {
+  return window['go']['main']['App']['GetStartMinimized']();
+}
+
 export function GetTimerStatus() {
   return window['go']['main']['App']['GetTimerStatus']();
 }
 
+export function GetWorkingHours() {
+  return window['go']['main']['App']['GetWorkingHours']();
+}
+
+export function HideBreakOverlay() {
+  return window['go']['main']['App']['HideBreakOverlay']();
+}
+
+export function IsWithinWorkingHours() {
+  return window['go']['main']['App']['IsWithinWorkingHours']();
+}
+
+export function ListProfiles() {
+  return window['go']['main']['App']['ListProfiles']();
+}
+
 export function PauseTimer() {
   return window['go']['main']['App']['PauseTimer']();
 }
 
+export function SetAlarmOutputDevice(arg1) {
+  return window['go']['main']['App']['SetAlarmOutputDevice'](arg1);
+}
+
+export function SetAmbientMix(arg1) {
+  return window['go']['main']['App']['SetAmbientMix'](arg1);
+}
+
+export function SetAmbientStopOnBreak(arg1) {
+  return window['go']['main']['App']['SetAmbientStopOnBreak'](arg1);
+}
+
+export function SetBreakOverlay(arg1) {
+  return window['go']['main']['App']['SetBreakOverlay'](arg1);
+}
+
+export function SetFocusLock(arg1, arg2) {
+  return window['go']['main']['App']['SetFocusLock'](arg1, arg2);
+}
+
+export function SetGentleAlarm(arg1) {
+  return window['go']['main']['App']['SetGentleAlarm'](arg1);
+}
+
+export function SetMuteAlarmsWhenMicActive(arg1) {
+  return window['go']['main']['App']['SetMuteAlarmsWhenMicActive'](arg1);
+}
+
+export function SetPresentationMode(arg1) {
+  return window['go']['main']['App']['SetPresentationMode'](arg1);
+}
+
+export function SetShortcut(arg1, arg2) {
+  return window['go']['main']['App']['SetShortcut'](arg1, arg2);
+}
+
+export function SetStartMinimized(arg1) {
+  return window['go']['main']['App']['SetStartMinimized'](arg1);
+}
+
+export function SetWorkingHours(arg1) {
+  return window['go']['main']['App']['SetWorkingHours'](arg1);
+}
+
+export function ShowBreakOverlay() {
+  return window['go']['main']['App']['ShowBreakOverlay']();
+}
+
+export function SkipBreak() {
+  return window['go']['main']['App']['SkipBreak']();
+}
+
+export function StartAmbient() {
+  return window['go']['main']['App']['StartAmbient']();
+}
+
 export function StartTimer() {
   return window['go']['main']['App']['StartTimer']();
 }
 
+export function StopAmbient() {
+  return window['go']['main']['App']['StopAmbient']();
+}
+
 export function StopTimer() {
   return window['go']['main']['App']['StopTimer']();
 }
 
+export function SwitchProfile(arg1) {
+  return window['go']['main']['App']['SwitchProfile'](arg1);
+}
+
+export function UnlockFocus(arg1) {
+  return window['go']['main']['App']['UnlockFocus'](arg1);
+}
+
+export function UpdateSessionSound(arg1, arg2) {
+  return window['go']['main']['App']['UpdateSessionSound'](arg1, arg2);
+}
+
 export function UpdateSettings(arg1, arg2, arg3, arg4) {
   return window['go']['main']['App']['UpdateSettings'](arg1, arg2, arg3, arg4);
 }
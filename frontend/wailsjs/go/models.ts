@@ -1,5 +1,245 @@
 export namespace main {
 	
+	export class AmbientLayer {
+	    sound: string;
+	    volume: number;
+	
+	    static createFrom(source: any = {}) {
+	        return new AmbientLayer(source);
+	    }
+	
+	    constructor(source: any = {}) {
+	        if ('string' === typeof source) source = JSON.parse(source);
+	        this.sound = source["sound"];
+	        this.volume = source["volume"];
+	    }
+	}
+	export class AmbientStatus {
+	    playing: boolean;
+	    layers: AmbientLayer[];
+	    stopOnBreak: boolean;
+	    fadeOut: boolean;
+	
+	    static createFrom(source: any = {}) {
+	        return new AmbientStatus(source);
+	    }
+	
+	    constructor(source: any = {}) {
+	        if ('string' === typeof source) source = JSON.parse(source);
+	        this.playing = source["playing"];
+	        this.layers = this.convertValues(source["layers"], AmbientLayer);
+	        this.stopOnBreak = source["stopOnBreak"];
+	        this.fadeOut = source["fadeOut"];
+	    }
+	
+		convertValues(a: any, classs: any, asMap: boolean = false): any {
+		    if (!a) {
+		        return a;
+		    }
+		    if (a.slice && a.map) {
+		        return (a as any[]).map(elem => this.convertValues(elem, classs));
+		    } else if ("object" === typeof a) {
+		        if (asMap) {
+		            for (const key of Object.keys(a)) {
+		                a[key] = new classs(a[key]);
+		            }
+		            return a;
+		        }
+		        return new classs(a);
+		    }
+		    return a;
+		}
+	}
+	export class FocusLockSettings {
+	    enabled: boolean;
+	    passcodeSalt: string;
+	    passcodeHash: string;
+	
+	    static createFrom(source: any = {}) {
+	        return new FocusLockSettings(source);
+	    }
+	
+	    constructor(source: any = {}) {
+	        if ('string' === typeof source) source = JSON.parse(source);
+	        this.enabled = source["enabled"];
+	        this.passcodeSalt = source["passcodeSalt"];
+	        this.passcodeHash = source["passcodeHash"];
+	    }
+	}
+	export class FocusLockStatus {
+	    enabled: boolean;
+	    hasPasscode: boolean;
+	    locked: boolean;
+	    phrase: string;
+	
+	    static createFrom(source: any = {}) {
+	        return new FocusLockStatus(source);
+	    }
+	
+	    constructor(source: any = {}) {
+	        if ('string' === typeof source) source = JSON.parse(source);
+	        this.enabled = source["enabled"];
+	        this.hasPasscode = source["hasPasscode"];
+	        this.locked = source["locked"];
+	        this.phrase = source["phrase"];
+	    }
+	}
+	export class HealthMetrics {
+	    uptimeSeconds: number;
+	    goroutines: number;
+	    heapAllocBytes: number;
+	    sysBytes: number;
+	    settingsPath: string;
+	    settingsFileBytes: number;
+	    pendingSettingsSave: boolean;
+	    profile: string;
+	    profileCount: number;
+	    microphoneDetectable: boolean;
+	
+	    static createFrom(source: any = {}) {
+	        return new HealthMetrics(source);
+	    }
+	
+	    constructor(source: any = {}) {
+	        if ('string' === typeof source) source = JSON.parse(source);
+	        this.uptimeSeconds = source["uptimeSeconds"];
+	        this.goroutines = source["goroutines"];
+	        this.heapAllocBytes = source["heapAllocBytes"];
+	        this.sysBytes = source["sysBytes"];
+	        this.settingsPath = source["settingsPath"];
+	        this.settingsFileBytes = source["settingsFileBytes"];
+	        this.pendingSettingsSave = source["pendingSettingsSave"];
+	        this.profile = source["profile"];
+	        this.profileCount = source["profileCount"];
+	        this.microphoneDetectable = source["microphoneDetectable"];
+	    }
+	}
+	export class MicrophoneStatus {
+	    muteAlarms: boolean;
+	    inUse: boolean;
+	    supported: boolean;
+	
+	    static createFrom(source: any = {}) {
+	        return new MicrophoneStatus(source);
+	    }
+	
+	    constructor(source: any = {}) {
+	        if ('string' === typeof source) source = JSON.parse(source);
+	        this.muteAlarms = source["muteAlarms"];
+	        this.inUse = source["inUse"];
+	        this.supported = source["supported"];
+	    }
+	}
+	export class ProfileList {
+	    active: string;
+	    profiles: string[];
+	
+	    static createFrom(source: any = {}) {
+	        return new ProfileList(source);
+	    }
+	
+	    constructor(source: any = {}) {
+	        if ('string' === typeof source) source = JSON.parse(source);
+	        this.active = source["active"];
+	        this.profiles = source["profiles"];
+	    }
+	}
+	export class SessionSound {
+	    sound: string;
+	    volume: number;
+	
+	    static createFrom(source: any = {}) {
+	        return new SessionSound(source);
+	    }
+	
+	    constructor(source: any = {}) {
+	        if ('string' === typeof source) source = JSON.parse(source);
+	        this.sound = source["sound"];
+	        this.volume = source["volume"];
+	    }
+	}
+	export class Settings {
+	    version: number;
+	    workDuration: number;
+	    shortBreakDuration: number;
+	    longBreakDuration: number;
+	    longBreakInterval: number;
+	    sounds: {[key: string]: SessionSound};
+	    alarmOutputDevice: string;
+	    gentleAlarm: boolean;
+	    muteAlarmsWhenMicActive: boolean;
+	    ambientLayers: AmbientLayer[];
+	    ambientStopOnBreak: boolean;
+	    workingHours: WorkingHours;
+	    breakOverlay: boolean;
+	    startMinimized: boolean;
+	    presentationMode: boolean;
+	    focusLock: FocusLockSettings;
+	    shortcuts: {[key: string]: string};
+	
+	    static createFrom(source: any = {}) {
+	        return new Settings(source);
+	    }
+	
+	    constructor(source: any = {}) {
+	        if ('string' === typeof source) source = JSON.parse(source);
+	        this.version = source["version"];
+	        this.workDuration = source["workDuration"];
+	        this.shortBreakDuration = source["shortBreakDuration"];
+	        this.longBreakDuration = source["longBreakDuration"];
+	        this.longBreakInterval = source["longBreakInterval"];
+	        this.sounds = this.convertValues(source["sounds"], SessionSound, true);
+	        this.alarmOutputDevice = source["alarmOutputDevice"];
+	        this.gentleAlarm = source["gentleAlarm"];
+	        this.muteAlarmsWhenMicActive = source["muteAlarmsWhenMicActive"];
+	        this.ambientLayers = this.convertValues(source["ambientLayers"], AmbientLayer);
+	        this.ambientStopOnBreak = source["ambientStopOnBreak"];
+	        this.workingHours = this.convertValues(source["workingHours"], WorkingHours);
+	        this.breakOverlay = source["breakOverlay"];
+	        this.startMinimized = source["startMinimized"];
+	        this.presentationMode = source["presentationMode"];
+	        this.focusLock = this.convertValues(source["focusLock"], FocusLockSettings);
+	        this.shortcuts = source["shortcuts"];
+	    }
+	
+		convertValues(a: any, classs: any, asMap: boolean = false): any {
+		    if (!a) {
+		        return a;
+		    }
+		    if (a.slice && a.map) {
+		        return (a as any[]).map(elem => this.convertValues(elem, classs));
+		    } else if ("object" === typeof a) {
+		        if (asMap) {
+		            for (const key of Object.keys(a)) {
+		                a[key] = new classs(a[key]);
+		            }
+		            return a;
+		        }
+		        return new classs(a);
+		    }
+		    return a;
+		}
+	}
+	export class SettingsLoadReport {
+	    path: string;
+	    substituted: string[];
+	    reason: string;
+	    restoredFromBackup: boolean;
+	    corruptCopy: string;
+	
+	    static createFrom(source: any = {}) {
+	        return new SettingsLoadReport(source);
+	    }
+	
+	    constructor(source: any = {}) {
+	        if ('string' === typeof source) source = JSON.parse(source);
+	        this.path = source["path"];
+	        this.substituted = source["substituted"];
+	        this.reason = source["reason"];
+	        this.restoredFromBackup = source["restoredFromBackup"];
+	        this.corruptCopy = source["corruptCopy"];
+	    }
+	}
 	export class TimerStatus {
 	    state: string;
 	    remainingTime: number;
@@ -18,6 +258,54 @@ export namespace main {
 	        this.completedPomodoros = source["completedPomodoros"];
 	    }
 	}
+	export class WorkingDay {
+	    enabled: boolean;
+	    start: string;
+	    end: string;
+	
+	    static createFrom(source: any = {}) {
+	        return new WorkingDay(source);
+	    }
+	
+	    constructor(source: any = {}) {
+	        if ('string' === typeof source) source = JSON.parse(source);
+	        this.enabled = source["enabled"];
+	        this.start = source["start"];
+	        this.end = source["end"];
+	    }
+	}
+	export class WorkingHours {
+	    enabled: boolean;
+	    days: WorkingDay[];
+	
+	    static createFrom(source: any = {}) {
+	        return new WorkingHours(source);
+	    }
+	
+	    constructor(source: any = {}) {
+	        if ('string' === typeof source) source = JSON.parse(source);
+	        this.enabled = source["enabled"];
+	        this.days = this.convertValues(source["days"], WorkingDay);
+	    }
+	
+		convertValues(a: any, classs: any, asMap: boolean = false): any {
+		    if (!a) {
+		        return a;
+		    }
+		    if (a.slice && a.map) {
+		        return (a as any[]).map(elem => this.convertValues(elem, classs));
+		    } else if ("object" === typeof a) {
+		        if (asMap) {
+		            for (const key of Object.keys(a)) {
+		                a[key] = new classs(a[key]);
+		            }
+		            return a;
+		        }
+		        return new classs(a);
+		    }
+		    return a;
+		}
+	}
 
 }
 
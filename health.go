@@ -0,0 +1,52 @@
+package main
+
+import (
+	"os"
+	goruntime "runtime"
+	"time"
+)
+
+// HealthMetrics summarizes the state of the running app for diagnostics
+type HealthMetrics struct {
+	UptimeSeconds        int64  `json:"uptimeSeconds"`
+	Goroutines           int    `json:"goroutines"`
+	HeapAllocBytes       uint64 `json:"heapAllocBytes"`
+	SysBytes             uint64 `json:"sysBytes"` // memory obtained from the OS
+	SettingsPath         string `json:"settingsPath"`
+	SettingsFileBytes    int64  `json:"settingsFileBytes"`
+	PendingSettingsSave  bool   `json:"pendingSettingsSave"`
+	Profile              string `json:"profile"`
+	ProfileCount         int    `json:"profileCount"`
+	MicrophoneDetectable bool   `json:"microphoneDetectable"`
+}
+
+// GetHealthMetrics returns memory usage, settings storage details and
+// other figures useful when diagnosing a sluggish install
+func (a *App) GetHealthMetrics() HealthMetrics {
+	var mem goruntime.MemStats
+	goruntime.ReadMemStats(&mem)
+
+	metrics := HealthMetrics{
+		Goroutines:     goruntime.NumGoroutine(),
+		HeapAllocBytes: mem.HeapAlloc,
+		SysBytes:       mem.Sys,
+		Profile:        a.activeProfile(),
+		ProfileCount:   len(a.ListProfiles().Profiles),
+	}
+	if !a.startedAt.IsZero() {
+		metrics.UptimeSeconds = int64(time.Since(a.startedAt).Seconds())
+	}
+	_, metrics.MicrophoneDetectable = microphoneInUse()
+
+	if store := a.currentStore(); store != nil {
+		store.mu.Lock()
+		metrics.SettingsPath = store.path
+		metrics.PendingSettingsSave = store.pending != nil
+		store.mu.Unlock()
+
+		if info, err := os.Stat(metrics.SettingsPath); err == nil {
+			metrics.SettingsFileBytes = info.Size()
+		}
+	}
+	return metrics
+}
@@ -2,6 +2,7 @@ package main
 
 import (
 	"embed"
+	"os"
 
 	"github.com/wailsapp/wails/v2"
 	"github.com/wailsapp/wails/v2/pkg/options"
@@ -15,6 +16,18 @@ func main() {
 	// Create an instance of the app structure
 	app := NewApp()
 
+	// Settings are loaded before the window exists so the start-minimized
+	// setting can decide how it opens
+	if err := app.loadSettings(); err != nil {
+		println("Warning:", err.Error())
+	}
+	app.launchMinimized = hasArg(os.Args[1:], "--minimized") || app.GetStartMinimized()
+
+	startState := options.Normal
+	if app.launchMinimized {
+		startState = options.Minimised
+	}
+
 	// Create application with options
 	err := wails.Run(&options.App{
 		Title:  "pomodoro-timer",
@@ -24,7 +37,13 @@ func main() {
 			Assets: assets,
 		},
 		BackgroundColour: &options.RGBA{R: 27, G: 38, B: 54, A: 1},
+		WindowStartState: startState,
+		Menu:             app.applicationMenu(),
 		OnStartup:        app.startup,
+		OnDomReady:       app.domReady,
+		OnBeforeClose:    app.beforeClose,
+		OnShutdown:       app.shutdown,
+		ErrorFormatter:   formatBindingError,
 		Bind: []interface{}{
 			app,
 		},
@@ -34,3 +53,13 @@ func main() {
 		println("Error:", err.Error())
 	}
 }
+
+// hasArg reports whether a command line flag was passed
+func hasArg(args []string, flag string) bool {
+	for _, arg := range args {
+		if arg == flag {
+			return true
+		}
+	}
+	return false
+}
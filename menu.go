@@ -0,0 +1,83 @@
+package main
+
+import (
+	goruntime "runtime"
+
+	"github.com/wailsapp/wails/v2/pkg/menu"
+	"github.com/wailsapp/wails/v2/pkg/runtime"
+)
+
+// applicationMenu builds the native menu bar. Every item calls the same
+// bound methods the frontend uses, so the window picks up the change on
+// its next status refresh. Accelerators come from the shortcut settings.
+func (a *App) applicationMenu() *menu.Menu {
+	appMenu := menu.NewMenu()
+	if goruntime.GOOS == "darwin" {
+		appMenu.Append(menu.AppMenu())
+		appMenu.Append(menu.EditMenu())
+	}
+
+	timerMenu := appMenu.AddSubmenu("Timer")
+	timerMenu.AddText("Start / Resume", a.accelerator("start"), func(_ *menu.CallbackData) {
+		a.StartTimer()
+	})
+	timerMenu.AddText("Pause", a.accelerator("pause"), func(_ *menu.CallbackData) {
+		a.PauseTimer()
+	})
+	timerMenu.AddText("Stop", a.accelerator("stop"), func(_ *menu.CallbackData) {
+		a.StopTimer()
+	})
+	timerMenu.AddSeparator()
+	timerMenu.AddText("Skip Break", a.accelerator("skipBreak"), func(_ *menu.CallbackData) {
+		a.SkipBreak()
+	})
+	timerMenu.AddText("Toggle Ambient Sound", a.accelerator("toggleAmbient"), func(_ *menu.CallbackData) {
+		if a.GetAmbientStatus().Playing {
+			a.StopAmbient()
+		} else {
+			a.StartAmbient()
+		}
+	})
+	if goruntime.GOOS != "darwin" {
+		timerMenu.AddSeparator()
+		timerMenu.AddText("Quit", a.accelerator("quit"), func(_ *menu.CallbackData) {
+			if a.checkUnlocked("quit") == nil {
+				runtime.Quit(a.ctx)
+			}
+		})
+	}
+
+	viewMenu := appMenu.AddSubmenu("View")
+	viewMenu.AddText("Toggle Fullscreen", a.accelerator("toggleFullscreen"), func(_ *menu.CallbackData) {
+		if runtime.WindowIsFullscreen(a.ctx) {
+			runtime.WindowUnfullscreen(a.ctx)
+		} else {
+			runtime.WindowFullscreen(a.ctx)
+		}
+	})
+	viewMenu.AddText("Show Break Overlay", a.accelerator("showBreakOverlay"), func(_ *menu.CallbackData) {
+		a.ShowBreakOverlay()
+	})
+	viewMenu.AddText("Hide Break Overlay", nil, func(_ *menu.CallbackData) {
+		a.HideBreakOverlay()
+	})
+	viewMenu.AddSeparator()
+	// Stays enabled in presentation mode, as it is the way back out
+	presentationItem := viewMenu.AddCheckbox("Presentation Mode", a.GetPresentationMode(), a.accelerator("presentationMode"), func(_ *menu.CallbackData) {
+		a.SetPresentationMode(!a.GetPresentationMode())
+	})
+	a.presentation.mu.Lock()
+	a.presentation.menuItem = presentationItem
+	a.presentation.mu.Unlock()
+
+	helpMenu := appMenu.AddSubmenu("Help")
+	helpMenu.AddText("About Pomodoro Timer", nil, func(_ *menu.CallbackData) {
+		runtime.MessageDialog(a.ctx, runtime.MessageDialogOptions{
+			Type:    runtime.InfoDialog,
+			Title:   "About Pomodoro Timer",
+			Message: "A focus timer with ambient sound and break reminders.",
+		})
+	})
+
+	return appMenu
+}
@@ -0,0 +1,31 @@
+//go:build linux
+
+package main
+
+import (
+	"os"
+	"path/filepath"
+	"strings"
+)
+
+// microphoneInUse reports whether any ALSA capture stream is running.
+// Sound servers only keep capture devices running while something is
+// recording, so a RUNNING capture substream is a good sign of a call.
+// The second result is false when the state could not be determined.
+func microphoneInUse() (bool, bool) {
+	paths, err := filepath.Glob("/proc/asound/card*/pcm*c/sub*/status")
+	if err != nil || len(paths) == 0 {
+		return false, false
+	}
+
+	for _, path := range paths {
+		data, err := os.ReadFile(path)
+		if err != nil {
+			continue
+		}
+		if strings.Contains(string(data), "state: RUNNING") {
+			return true, true
+		}
+	}
+	return false, true
+}
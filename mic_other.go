@@ -0,0 +1,8 @@
+//go:build !linux && !windows
+
+package main
+
+// microphoneInUse cannot detect microphone usage on this platform
+func microphoneInUse() (bool, bool) {
+	return false, false
+}
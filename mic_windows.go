@@ -0,0 +1,55 @@
+//go:build windows
+
+package main
+
+import (
+	"golang.org/x/sys/windows/registry"
+)
+
+// microphoneConsentStore is where Windows tracks microphone usage per app
+const microphoneConsentStore = `Software\Microsoft\Windows\CurrentVersion\CapabilityAccessManager\ConsentStore\microphone`
+
+// microphoneInUse reports whether any app is currently capturing from
+// the microphone. The second result is false when the state could not
+// be determined.
+func microphoneInUse() (bool, bool) {
+	key, err := registry.OpenKey(registry.CURRENT_USER, microphoneConsentStore, registry.ENUMERATE_SUB_KEYS)
+	if err != nil {
+		return false, false
+	}
+	defer key.Close()
+
+	return consentKeyInUse(key), true
+}
+
+// consentKeyInUse looks for an app entry that started using the
+// microphone and has not stopped yet. Desktop apps are grouped under
+// a NonPackaged subkey.
+func consentKeyInUse(key registry.Key) bool {
+	names, err := key.ReadSubKeyNames(-1)
+	if err != nil {
+		return false
+	}
+
+	for _, name := range names {
+		sub, err := registry.OpenKey(key, name, registry.QUERY_VALUE|registry.ENUMERATE_SUB_KEYS)
+		if err != nil {
+			continue
+		}
+
+		inUse := false
+		if name == "NonPackaged" {
+			inUse = consentKeyInUse(sub)
+		} else {
+			start, _, startErr := sub.GetIntegerValue("LastUsedTimeStart")
+			stop, _, stopErr := sub.GetIntegerValue("LastUsedTimeStop")
+			inUse = startErr == nil && stopErr == nil && start > 0 && stop == 0
+		}
+		sub.Close()
+
+		if inUse {
+			return true
+		}
+	}
+	return false
+}
@@ -0,0 +1,126 @@
+package main
+
+import (
+	"sync"
+
+	"github.com/wailsapp/wails/v2/pkg/runtime"
+)
+
+// BreakOverlay tracks the fullscreen overlay shown during breaks.
+// The overlay itself is drawn by the frontend over the main window,
+// which the backend switches to fullscreen while it is visible.
+type BreakOverlay struct {
+	mu      sync.Mutex
+	enabled bool // show automatically when a break starts
+	visible bool
+}
+
+// NewBreakOverlay creates an overlay that is only shown on request
+func NewBreakOverlay() *BreakOverlay {
+	return &BreakOverlay{}
+}
+
+// GetBreakOverlay reports whether the overlay opens automatically on breaks
+func (a *App) GetBreakOverlay() bool {
+	a.overlay.mu.Lock()
+	defer a.overlay.mu.Unlock()
+	return a.overlay.enabled
+}
+
+// SetBreakOverlay configures whether the overlay opens automatically on breaks
+func (a *App) SetBreakOverlay(enabled bool) error {
+	if err := a.checkWritable(); err != nil {
+		return err
+	}
+
+	a.overlay.mu.Lock()
+	a.overlay.enabled = enabled
+	a.overlay.mu.Unlock()
+
+	a.persistSettings()
+	return nil
+}
+
+// ShowBreakOverlay brings the window to the front in fullscreen and
+// tells the frontend to cover it with the break countdown
+func (a *App) ShowBreakOverlay() error {
+	if err := a.checkWritable(); err != nil {
+		return err
+	}
+	a.showBreakOverlay("")
+	return nil
+}
+
+// HideBreakOverlay leaves fullscreen and removes the overlay
+func (a *App) HideBreakOverlay() error {
+	if err := a.checkWritable(); err != nil {
+		return err
+	}
+	a.hideBreakOverlay()
+	return nil
+}
+
+// showBreakOverlay shows the overlay. If phase is set, it is only shown
+// while the timer is still in that phase, so a break that was stopped in
+// the meantime does not cover the window.
+func (a *App) showBreakOverlay(phase TimerState) {
+	a.overlay.mu.Lock()
+	if a.overlay.visible || a.ctx == nil || (phase != "" && a.GetTimerStatus().State != phase) {
+		a.overlay.mu.Unlock()
+		return
+	}
+	a.overlay.visible = true
+	a.overlay.mu.Unlock()
+
+	runtime.WindowShow(a.ctx)
+	runtime.WindowUnminimise(a.ctx)
+	runtime.WindowFullscreen(a.ctx)
+	a.emit("break-overlay", true)
+}
+
+// hideBreakOverlay removes the overlay when the break is over
+func (a *App) hideBreakOverlay() {
+	a.overlay.mu.Lock()
+	if !a.overlay.visible || a.ctx == nil {
+		a.overlay.mu.Unlock()
+		return
+	}
+	a.overlay.visible = false
+	a.overlay.mu.Unlock()
+
+	runtime.WindowUnfullscreen(a.ctx)
+	a.emit("break-overlay", false)
+}
+
+// SkipBreak ends the current break and starts the next focus session
+func (a *App) SkipBreak() (TimerStatus, error) {
+	if err := a.checkWritable(); err != nil {
+		return a.GetTimerStatus(), err
+	}
+
+	a.timer.mu.Lock()
+	if a.timer.state == StateBreak || a.timer.state == StateLongBreak {
+		a.timer.state = StateWorking
+		a.timer.currentCycle++
+		a.timer.remainingTime = a.timer.workDuration * 60
+	}
+	status := a.getStatus()
+	a.timer.mu.Unlock()
+
+	a.hideBreakOverlay()
+	if status.State == StateWorking {
+		a.focusLock.engage()
+	}
+	return status, nil
+}
+
+// updateBreakOverlay shows or hides the overlay when the timer changes phase
+func (a *App) updateBreakOverlay(next TimerState) {
+	if next == StateBreak || next == StateLongBreak {
+		if a.GetBreakOverlay() {
+			a.showBreakOverlay(next)
+		}
+		return
+	}
+	a.hideBreakOverlay()
+}
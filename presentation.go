@@ -0,0 +1,65 @@
+package main
+
+import (
+	"errors"
+	"sync"
+
+	"github.com/wailsapp/wails/v2/pkg/menu"
+	"github.com/wailsapp/wails/v2/pkg/runtime"
+)
+
+// errPresentationMode is returned by every command that would change
+// state while presentation mode is on
+var errPresentationMode = errors.New("presentation mode is on; turn it off to make changes")
+
+// PresentationMode makes the app read-only, for streaming or sharing the screen
+type PresentationMode struct {
+	mu       sync.RWMutex
+	enabled  bool
+	menuItem *menu.MenuItem // View menu checkbox, kept in step with enabled
+}
+
+// NewPresentationMode creates presentation mode switched off
+func NewPresentationMode() *PresentationMode {
+	return &PresentationMode{}
+}
+
+// checkWritable rejects mutating commands while presentation mode is on
+func (a *App) checkWritable() error {
+	if a.GetPresentationMode() {
+		return errPresentationMode
+	}
+	return nil
+}
+
+// GetPresentationMode reports whether the app is read-only
+func (a *App) GetPresentationMode() bool {
+	a.presentation.mu.RLock()
+	defer a.presentation.mu.RUnlock()
+	return a.presentation.enabled
+}
+
+// SetPresentationMode turns read-only presentation mode on or off. While
+// it is on, the timer keeps running but every command that changes the
+// timer, settings or profiles fails with an error.
+func (a *App) SetPresentationMode(enabled bool) {
+	a.presentation.mu.Lock()
+	a.presentation.enabled = enabled
+	a.presentation.mu.Unlock()
+
+	a.syncPresentationMenu()
+	a.persistSettings()
+}
+
+// syncPresentationMenu updates the View menu checkbox after the mode changed
+func (a *App) syncPresentationMenu() {
+	a.presentation.mu.RLock()
+	item, enabled := a.presentation.menuItem, a.presentation.enabled
+	a.presentation.mu.RUnlock()
+
+	if item == nil || a.ctx == nil {
+		return
+	}
+	item.Checked = enabled
+	runtime.MenuUpdateApplicationMenu(a.ctx)
+}
@@ -0,0 +1,188 @@
+package main
+
+import (
+	"encoding/json"
+	"fmt"
+	"os"
+	"path/filepath"
+	"regexp"
+	"sort"
+	"strings"
+)
+
+// defaultProfile is the profile backed by the original settings.json
+const defaultProfile = "Default"
+
+// profileNamePattern limits profile names to ones that are safe as file names
+var profileNamePattern = regexp.MustCompile(`^[A-Za-z0-9][A-Za-z0-9 _-]{0,31}$`)
+
+// reservedProfileNames are device names on Windows, where CON.json still opens the device
+var reservedProfileNames = regexp.MustCompile(`(?i)^(CON|PRN|AUX|NUL|COM[1-9]|LPT[1-9])$`)
+
+// ProfileList describes the available profiles and the active one
+type ProfileList struct {
+	Active   string   `json:"active"`
+	Profiles []string `json:"profiles"`
+}
+
+// profileState is stored in profiles.json next to settings.json
+type profileState struct {
+	Active string `json:"active"`
+}
+
+// validProfileName reports whether a name can safely be used as a profile file name
+func validProfileName(name string) bool {
+	if name == defaultProfile {
+		return true
+	}
+	return profileNamePattern.MatchString(name) && !reservedProfileNames.MatchString(strings.TrimRight(name, " "))
+}
+
+// profileSettingsPath returns the settings file for a profile
+func (a *App) profileSettingsPath(name string) string {
+	if name == defaultProfile {
+		return filepath.Join(a.configDir, "settings.json")
+	}
+	return filepath.Join(a.configDir, "profiles", name+".json")
+}
+
+// activeProfile returns the profile whose settings are currently loaded
+func (a *App) activeProfile() string {
+	a.storeMu.RLock()
+	defer a.storeMu.RUnlock()
+
+	if a.profile == "" {
+		return defaultProfile
+	}
+	return a.profile
+}
+
+// loadActiveProfile switches to the profile recorded in profiles.json, if any
+// (must be called with storeMu held)
+func (a *App) loadActiveProfile() {
+	data, err := os.ReadFile(filepath.Join(a.configDir, "profiles.json"))
+	if err != nil {
+		return
+	}
+
+	var state profileState
+	if err := json.Unmarshal(data, &state); err != nil || state.Active == defaultProfile || !validProfileName(state.Active) {
+		return
+	}
+	if _, err := os.Stat(a.profileSettingsPath(state.Active)); err != nil {
+		return
+	}
+
+	a.profile = state.Active
+	a.store = a.newSettingsStore(a.profileSettingsPath(state.Active))
+	a.applySettings(a.store.load())
+}
+
+// saveActiveProfile records the active profile in profiles.json
+func (a *App) saveActiveProfile() error {
+	data, err := json.MarshalIndent(profileState{Active: a.activeProfile()}, "", "  ")
+	if err != nil {
+		return fmt.Errorf("failed to encode profile state: %w", err)
+	}
+	if err := os.WriteFile(filepath.Join(a.configDir, "profiles.json"), data, 0o644); err != nil {
+		return fmt.Errorf("failed to write profile state: %w", err)
+	}
+	return nil
+}
+
+// ListProfiles returns every profile and the active one
+func (a *App) ListProfiles() ProfileList {
+	profiles := []string{defaultProfile}
+	if a.currentStore() != nil {
+		matches, _ := filepath.Glob(filepath.Join(a.configDir, "profiles", "*.json"))
+		for _, match := range matches {
+			name := strings.TrimSuffix(filepath.Base(match), ".json")
+			if name != defaultProfile && validProfileName(name) {
+				profiles = append(profiles, name)
+			}
+		}
+	}
+	sort.Strings(profiles[1:])
+
+	return ProfileList{
+		Active:   a.activeProfile(),
+		Profiles: profiles,
+	}
+}
+
+// CreateProfile creates a new profile starting from the default settings
+func (a *App) CreateProfile(name string) (ProfileList, error) {
+	if err := a.checkWritable(); err != nil {
+		return a.ListProfiles(), err
+	}
+
+	if a.currentStore() == nil {
+		return a.ListProfiles(), fmt.Errorf("settings storage is not available")
+	}
+	if !profileNamePattern.MatchString(name) {
+		return a.ListProfiles(), fmt.Errorf("profile names must be 1-32 letters, digits, spaces, dashes or underscores")
+	}
+	if !validProfileName(name) {
+		return a.ListProfiles(), fmt.Errorf("%q is reserved by the operating system", name)
+	}
+	path := a.profileSettingsPath(name)
+	if _, err := os.Stat(path); err == nil || name == defaultProfile {
+		return a.ListProfiles(), fmt.Errorf("profile %q already exists", name)
+	}
+
+	store := &settingsStore{path: path}
+	if err := store.save(defaultSettings()); err != nil {
+		return a.ListProfiles(), err
+	}
+	return a.ListProfiles(), nil
+}
+
+// SwitchProfile saves the current profile, stops the timer and loads the
+// settings of another profile. The frontend is notified with a
+// "profile-switched" event so it can refresh anything it has cached.
+func (a *App) SwitchProfile(name string) (ProfileList, error) {
+	if err := a.checkWritable(); err != nil {
+		return a.ListProfiles(), err
+	}
+	if err := a.checkUnlocked("switch-profile"); err != nil {
+		return a.ListProfiles(), err
+	}
+
+	if a.currentStore() == nil {
+		return a.ListProfiles(), fmt.Errorf("settings storage is not available")
+	}
+	if !validProfileName(name) {
+		return a.ListProfiles(), fmt.Errorf("profile %q does not exist", name)
+	}
+	if name == a.activeProfile() {
+		return a.ListProfiles(), nil
+	}
+	path := a.profileSettingsPath(name)
+	if name != defaultProfile {
+		if _, err := os.Stat(path); err != nil {
+			return a.ListProfiles(), fmt.Errorf("profile %q does not exist", name)
+		}
+	}
+
+	a.StopTimer()
+	a.StopAmbient()
+
+	a.storeMu.Lock()
+	a.flushStore()
+	a.profile = name
+	a.store = a.newSettingsStore(path)
+	a.applySettings(a.store.load())
+	a.storeMu.Unlock()
+
+	if err := a.saveActiveProfile(); err != nil {
+		return a.ListProfiles(), err
+	}
+
+	profiles := a.ListProfiles()
+	a.emit("profile-switched", profiles)
+	a.emit("settings-changed", a.currentSettings().public())
+	a.emit("ambient-changed", a.GetAmbientStatus())
+	a.refreshMenu()
+	a.reportSettingsDefaults()
+	return profiles, nil
+}
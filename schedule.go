@@ -0,0 +1,171 @@
+package main
+
+import (
+	"fmt"
+	"sync"
+	"time"
+)
+
+// WorkingDay is the working time for one day of the week, as "HH:MM" in local time
+type WorkingDay struct {
+	Enabled bool   `json:"enabled"`
+	Start   string `json:"start"`
+	End     string `json:"end"`
+}
+
+// WorkingHours is the weekly working-hours schedule.
+// Days is indexed by time.Weekday, starting with Sunday.
+type WorkingHours struct {
+	Enabled bool         `json:"enabled"`
+	Days    []WorkingDay `json:"days"`
+}
+
+// WorkingSchedule holds the working-hours configuration
+type WorkingSchedule struct {
+	mu    sync.RWMutex
+	hours WorkingHours
+}
+
+// NewWorkingSchedule creates a disabled schedule covering 09:00-17:00 on weekdays
+func NewWorkingSchedule() *WorkingSchedule {
+	days := make([]WorkingDay, 7)
+	for i := range days {
+		weekday := time.Weekday(i)
+		days[i] = WorkingDay{
+			Enabled: weekday != time.Saturday && weekday != time.Sunday,
+			Start:   "09:00",
+			End:     "17:00",
+		}
+	}
+	return &WorkingSchedule{hours: WorkingHours{Days: days}}
+}
+
+// parseClock converts "HH:MM" to minutes after midnight
+func parseClock(value string) (int, error) {
+	parsed, err := time.Parse("15:04", value)
+	if err != nil {
+		return 0, fmt.Errorf("invalid time %q, expected HH:MM", value)
+	}
+	return parsed.Hour()*60 + parsed.Minute(), nil
+}
+
+// validateWorkingHours checks that every day has a valid time range
+func validateWorkingHours(hours WorkingHours) ValidationErrors {
+	if len(hours.Days) != 7 {
+		return ValidationErrors{{Field: "days", Message: "working hours must list 7 days"}}
+	}
+
+	var errs ValidationErrors
+	for i, day := range hours.Days {
+		field := fmt.Sprintf("days[%d]", i)
+		start, startErr := parseClock(day.Start)
+		if startErr != nil {
+			errs = append(errs, FieldError{Field: field + ".start", Message: fmt.Sprintf("%s: %v", time.Weekday(i), startErr)})
+		}
+		end, endErr := parseClock(day.End)
+		if endErr != nil {
+			errs = append(errs, FieldError{Field: field + ".end", Message: fmt.Sprintf("%s: %v", time.Weekday(i), endErr)})
+		}
+		if startErr == nil && endErr == nil && end <= start {
+			errs = append(errs, FieldError{Field: field + ".end", Message: fmt.Sprintf("%s: end must be after start", time.Weekday(i))})
+		}
+	}
+	return errs
+}
+
+// contains reports whether t falls inside working hours.
+// A disabled schedule treats every moment as working time.
+func (s *WorkingSchedule) contains(t time.Time) bool {
+	s.mu.RLock()
+	defer s.mu.RUnlock()
+
+	if !s.hours.Enabled || len(s.hours.Days) != 7 {
+		return true
+	}
+	day := s.hours.Days[t.Weekday()]
+	if !day.Enabled {
+		return false
+	}
+
+	start, err := parseClock(day.Start)
+	if err != nil {
+		return true
+	}
+	end, err := parseClock(day.End)
+	if err != nil {
+		return true
+	}
+	minute := t.Hour()*60 + t.Minute()
+	return minute >= start && minute < end
+}
+
+// GetWorkingHours returns the weekly working-hours schedule
+func (a *App) GetWorkingHours() WorkingHours {
+	a.schedule.mu.RLock()
+	defer a.schedule.mu.RUnlock()
+
+	hours := a.schedule.hours
+	hours.Days = append([]WorkingDay(nil), hours.Days...)
+	return hours
+}
+
+// SetWorkingHours replaces the weekly working-hours schedule
+func (a *App) SetWorkingHours(hours WorkingHours) error {
+	if err := a.checkWritable(); err != nil {
+		return err
+	}
+
+	if errs := validateWorkingHours(hours); len(errs) > 0 {
+		return errs
+	}
+
+	a.schedule.mu.Lock()
+	a.schedule.hours = WorkingHours{
+		Enabled: hours.Enabled,
+		Days:    append([]WorkingDay(nil), hours.Days...),
+	}
+	a.schedule.mu.Unlock()
+
+	a.persistSettings()
+	return nil
+}
+
+// IsWithinWorkingHours reports whether the current time is inside working hours
+func (a *App) IsWithinWorkingHours() bool {
+	return a.schedule.contains(time.Now())
+}
+
+// allowAutoContinue decides whether the timer keeps cycling into the next
+// phase. Sessions that continue automatically stop at the end of the
+// working day instead of starting another focus session.
+// It runs with the timer lock held and must not call back into the timer.
+func (a *App) allowAutoContinue(next TimerState) bool {
+	return next != StateWorking || a.schedule.contains(time.Now())
+}
+
+// workdayCheckInterval is how often the end of working hours is checked
+const workdayCheckInterval = time.Minute
+
+// WorkdayWrapUp is emitted when working hours end while a session is still open
+type WorkdayWrapUp struct {
+	Timer TimerStatus `json:"timer"`
+}
+
+// watchWorkday emits "workday-wrap-up" once at the end of working hours if
+// the timer is still running or paused, so the user can wrap up the day
+func (a *App) watchWorkday() {
+	ticker := time.NewTicker(workdayCheckInterval)
+	defer ticker.Stop()
+
+	wasInHours := a.schedule.contains(time.Now())
+	for now := range ticker.C {
+		inHours := a.schedule.contains(now)
+		if wasInHours && !inHours {
+			status := a.GetTimerStatus()
+			if status.State != StateIdle {
+				a.emit("workday-wrap-up", WorkdayWrapUp{Timer: status})
+			}
+		}
+		wasInHours = inHours
+	}
+}
@@ -0,0 +1,530 @@
+package main
+
+import (
+	"bytes"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"os"
+	"path/filepath"
+	"sort"
+	"sync"
+	"time"
+
+	"github.com/wailsapp/wails/v2/pkg/runtime"
+)
+
+// currentSettingsVersion is the schema version written to settings.json.
+// Bump it together with a new entry in settingsMigrations whenever a key
+// is renamed or changes type.
+const currentSettingsVersion = 1
+
+// settingsSaveDelay batches rapid changes, like dragging a volume slider,
+// into a single write
+const settingsSaveDelay = 500 * time.Millisecond
+
+// settingsMigrations upgrade the raw settings document one version at a
+// time: settingsMigrations[i] turns version i+1 into version i+2.
+var settingsMigrations = []func(raw map[string]json.RawMessage) error{}
+
+// Settings is the persisted user configuration
+type Settings struct {
+	Version                 int                         `json:"version"`
+	WorkDuration            int                         `json:"workDuration"`
+	ShortBreakDuration      int                         `json:"shortBreakDuration"`
+	LongBreakDuration       int                         `json:"longBreakDuration"`
+	LongBreakInterval       int                         `json:"longBreakInterval"`
+	Sounds                  map[TimerState]SessionSound `json:"sounds"`
+	AlarmOutputDevice       string                      `json:"alarmOutputDevice"`
+	GentleAlarm             bool                        `json:"gentleAlarm"`
+	MuteAlarmsWhenMicActive bool                        `json:"muteAlarmsWhenMicActive"`
+	AmbientLayers           []AmbientLayer              `json:"ambientLayers"`
+	AmbientStopOnBreak      bool                        `json:"ambientStopOnBreak"`
+	WorkingHours            WorkingHours                `json:"workingHours"`
+	BreakOverlay            bool                        `json:"breakOverlay"`
+	StartMinimized          bool                        `json:"startMinimized"`
+	PresentationMode        bool                        `json:"presentationMode"`
+	FocusLock               FocusLockSettings           `json:"focusLock"`
+	Shortcuts               map[string]string           `json:"shortcuts"`
+}
+
+// SettingsLoadReport describes problems found while loading settings.json
+type SettingsLoadReport struct {
+	Path        string   `json:"path"`
+	Substituted []string `json:"substituted"` // keys that fell back to their defaults
+	Reason      string   `json:"reason"`
+
+	RestoredFromBackup bool   `json:"restoredFromBackup"`
+	CorruptCopy        string `json:"corruptCopy"` // where a damaged file was moved, if anywhere
+}
+
+// settingsStore reads and writes settings.json
+type settingsStore struct {
+	mu     sync.Mutex
+	path   string
+	report *SettingsLoadReport
+
+	saved    []byte      // contents last read or written, to skip unchanged saves
+	readOnly error       // why the file on disk must not be overwritten, if it must not
+	pending  *Settings   // settings waiting for the debounce timer
+	timer    *time.Timer // fires settingsSaveDelay after the last change
+	onError  func(error) // reports failures of debounced saves
+}
+
+// defaultSettings returns the settings used on first run
+func defaultSettings() Settings {
+	timer := NewPomodoroTimer()
+	sounds := NewSoundSettings()
+	ambient := NewAmbientMixer()
+	schedule := NewWorkingSchedule()
+
+	return Settings{
+		Version:                 currentSettingsVersion,
+		WorkDuration:            timer.workDuration,
+		ShortBreakDuration:      timer.shortBreakDuration,
+		LongBreakDuration:       timer.longBreakDuration,
+		LongBreakInterval:       timer.longBreakInterval,
+		Sounds:                  sounds.sessions,
+		AlarmOutputDevice:       sounds.outputDevice,
+		GentleAlarm:             sounds.gentleAlarm,
+		MuteAlarmsWhenMicActive: sounds.muteOnMic,
+		AmbientLayers:           ambient.layers,
+		AmbientStopOnBreak:      ambient.stopOnBreak,
+		WorkingHours:            schedule.hours,
+		Shortcuts:               withDefaultShortcuts(nil),
+	}
+}
+
+// defaultSettingsPath returns settings.json inside the user config directory
+func defaultSettingsPath() (string, error) {
+	dir, err := os.UserConfigDir()
+	if err != nil {
+		return "", fmt.Errorf("failed to locate config directory: %w", err)
+	}
+	return filepath.Join(dir, "pomodoro-timer", "settings.json"), nil
+}
+
+// migrateSettings upgrades a raw settings document to the current version
+func migrateSettings(raw map[string]json.RawMessage) error {
+	version := 1
+	if data, ok := raw["version"]; ok {
+		if err := json.Unmarshal(data, &version); err != nil {
+			return fmt.Errorf("invalid settings version: %w", err)
+		}
+	}
+	if version > currentSettingsVersion {
+		return fmt.Errorf("%w: version %d is newer than supported version %d", errSettingsNewer, version, currentSettingsVersion)
+	}
+
+	for ; version < currentSettingsVersion; version++ {
+		if err := settingsMigrations[version-1](raw); err != nil {
+			return fmt.Errorf("failed to migrate settings from version %d: %w", version, err)
+		}
+	}
+	raw["version"], _ = json.Marshal(currentSettingsVersion)
+	return nil
+}
+
+// decodeSettings decodes a migrated document key by key on top of the
+// defaults, so one unreadable or out-of-range value only resets that
+// value. It returns the keys that had to fall back to their defaults.
+func decodeSettings(raw map[string]json.RawMessage) (Settings, []string) {
+	merged := make(map[string]json.RawMessage)
+	defaults, _ := json.Marshal(defaultSettings())
+	json.Unmarshal(defaults, &merged)
+
+	keys := make([]string, 0, len(raw))
+	for key := range raw {
+		if _, known := merged[key]; known {
+			keys = append(keys, key)
+		}
+	}
+	sort.Strings(keys)
+
+	var substituted []string
+	for _, key := range keys {
+		previous := merged[key]
+		merged[key] = raw[key]
+
+		data, _ := json.Marshal(merged)
+		var trial Settings
+		if err := json.Unmarshal(data, &trial); err != nil || validateSettings(trial).has(key) {
+			merged[key] = previous
+			substituted = append(substituted, key)
+		}
+	}
+
+	data, _ := json.Marshal(merged)
+	settings := defaultSettings()
+	json.Unmarshal(data, &settings)
+	return settings, substituted
+}
+
+// errSettingsCorrupt marks a settings file that cannot be parsed or migrated
+var errSettingsCorrupt = errors.New("settings file is damaged")
+
+// errSettingsNewer means the settings file was written by a newer version
+var errSettingsNewer = errors.New("settings file is from a newer version")
+
+// readSettingsFile parses and migrates one settings file, returning the
+// keys that fell back to their defaults
+func readSettingsFile(path string) (Settings, []string, error) {
+	data, err := os.ReadFile(path)
+	if err != nil {
+		return defaultSettings(), nil, err
+	}
+
+	raw := make(map[string]json.RawMessage)
+	if err := json.Unmarshal(data, &raw); err != nil {
+		return defaultSettings(), nil, fmt.Errorf("%w: not valid JSON: %v", errSettingsCorrupt, err)
+	}
+	if err := migrateSettings(raw); err != nil {
+		if errors.Is(err, errSettingsNewer) {
+			return defaultSettings(), nil, err
+		}
+		return defaultSettings(), nil, fmt.Errorf("%w: %v", errSettingsCorrupt, err)
+	}
+
+	settings, substituted := decodeSettings(raw)
+	return settings, substituted, nil
+}
+
+// load reads settings.json, falling back to defaults where it cannot.
+// A damaged file is moved aside to settings.json.corrupt and the last
+// backup written by save is restored in its place.
+func (s *settingsStore) load() Settings {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+
+	settings, substituted, err := readSettingsFile(s.path)
+	if os.IsNotExist(err) {
+		return settings
+	}
+	if errors.Is(err, errSettingsCorrupt) {
+		return s.restoreBackup(err)
+	}
+	if err != nil {
+		// A newer file, or one that is locked or not readable right now,
+		// is kept intact rather than replaced with defaults
+		s.readOnly = err
+		s.report = &SettingsLoadReport{
+			Path:   s.path,
+			Reason: fmt.Sprintf("%v; changes will not be saved", err),
+		}
+		return settings
+	}
+
+	if len(substituted) > 0 {
+		s.report = &SettingsLoadReport{
+			Path:        s.path,
+			Substituted: substituted,
+			Reason:      "some settings could not be read and were reset to their defaults",
+		}
+	} else {
+		s.saved, _ = encodeSettings(settings)
+	}
+	return settings
+}
+
+// restoreBackup replaces a damaged settings file with its backup, or with the
+// defaults if no usable backup exists (must be called with lock held)
+func (s *settingsStore) restoreBackup(cause error) Settings {
+	corrupt := s.path + ".corrupt"
+	if err := os.Rename(s.path, corrupt); err != nil {
+		corrupt = ""
+	}
+
+	settings, substituted, err := readSettingsFile(s.path + ".bak")
+	if err != nil {
+		s.report = &SettingsLoadReport{
+			Path:        s.path,
+			Reason:      fmt.Sprintf("%v; defaults are in use", cause),
+			CorruptCopy: corrupt,
+		}
+		return defaultSettings()
+	}
+
+	s.report = &SettingsLoadReport{
+		Path:               s.path,
+		Substituted:        substituted,
+		Reason:             fmt.Sprintf("%v; the last backup was restored", cause),
+		RestoredFromBackup: true,
+		CorruptCopy:        corrupt,
+	}
+	s.write(settings)
+	return settings
+}
+
+// encodeSettings renders settings the way they are stored on disk
+func encodeSettings(settings Settings) ([]byte, error) {
+	settings.Version = currentSettingsVersion
+	data, err := json.MarshalIndent(settings, "", "  ")
+	if err != nil {
+		return nil, fmt.Errorf("failed to encode settings: %w", err)
+	}
+	return data, nil
+}
+
+// save writes settings.json immediately, dropping any pending save
+func (s *settingsStore) save(settings Settings) error {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+
+	s.cancelPending()
+	return s.write(settings)
+}
+
+// scheduleSave writes settings once no further change arrives for settingsSaveDelay
+func (s *settingsStore) scheduleSave(settings Settings) {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+
+	s.pending = &settings
+	if s.timer != nil {
+		s.timer.Reset(settingsSaveDelay)
+		return
+	}
+	s.timer = time.AfterFunc(settingsSaveDelay, func() {
+		if err := s.flush(); err != nil && s.onError != nil {
+			s.onError(err)
+		}
+	})
+}
+
+// flush writes a pending save right away, if there is one
+func (s *settingsStore) flush() error {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+
+	if s.pending == nil {
+		return nil
+	}
+	settings := *s.pending
+	s.cancelPending()
+	return s.write(settings)
+}
+
+// cancelPending drops a pending save (must be called with lock held)
+func (s *settingsStore) cancelPending() {
+	if s.timer != nil {
+		s.timer.Stop()
+		s.timer = nil
+	}
+	s.pending = nil
+}
+
+// write stores settings atomically unless they match what is already on
+// disk (must be called with lock held)
+func (s *settingsStore) write(settings Settings) error {
+	if s.readOnly != nil {
+		return fmt.Errorf("not saving settings: %w", s.readOnly)
+	}
+	data, err := encodeSettings(settings)
+	if err != nil {
+		return err
+	}
+	if bytes.Equal(data, s.saved) {
+		return nil
+	}
+
+	if err := os.MkdirAll(filepath.Dir(s.path), 0o755); err != nil {
+		return fmt.Errorf("failed to create settings directory: %w", err)
+	}
+	if s.saved != nil {
+		// Keep the previous good version around for recovery
+		os.WriteFile(s.path+".bak", s.saved, 0o644)
+	}
+
+	tmp := s.path + ".tmp"
+	if err := os.WriteFile(tmp, data, 0o644); err != nil {
+		return fmt.Errorf("failed to write settings: %w", err)
+	}
+	if err := os.Rename(tmp, s.path); err != nil {
+		return fmt.Errorf("failed to replace settings: %w", err)
+	}
+	s.saved = data
+	return nil
+}
+
+// loadSettings reads the settings file and applies it to every subsystem
+func (a *App) loadSettings() error {
+	path, err := defaultSettingsPath()
+	if err != nil {
+		return fmt.Errorf("settings will not be persisted: %w", err)
+	}
+
+	a.configDir = filepath.Dir(path)
+
+	a.storeMu.Lock()
+	defer a.storeMu.Unlock()
+	a.store = a.newSettingsStore(path)
+	a.applySettings(a.store.load())
+	a.loadActiveProfile()
+	return nil
+}
+
+// currentStore returns the settings store of the active profile, if any
+func (a *App) currentStore() *settingsStore {
+	a.storeMu.RLock()
+	defer a.storeMu.RUnlock()
+	return a.store
+}
+
+// newSettingsStore creates a store that logs failed background saves
+func (a *App) newSettingsStore(path string) *settingsStore {
+	return &settingsStore{
+		path: path,
+		onError: func(err error) {
+			runtime.LogErrorf(a.ctx, "%v", err)
+		},
+	}
+}
+
+// applySettings pushes loaded settings into the timer, sounds and ambient mixer
+func (a *App) applySettings(settings Settings) {
+	a.timer.mu.Lock()
+	a.timer.workDuration = settings.WorkDuration
+	a.timer.shortBreakDuration = settings.ShortBreakDuration
+	a.timer.longBreakDuration = settings.LongBreakDuration
+	a.timer.longBreakInterval = settings.LongBreakInterval
+	a.timer.mu.Unlock()
+
+	a.sounds.mu.Lock()
+	for state, sound := range settings.Sounds {
+		a.sounds.sessions[state] = sound
+	}
+	a.sounds.outputDevice = settings.AlarmOutputDevice
+	a.sounds.gentleAlarm = settings.GentleAlarm
+	a.sounds.muteOnMic = settings.MuteAlarmsWhenMicActive
+	a.sounds.mu.Unlock()
+
+	a.ambient.mu.Lock()
+	a.ambient.layers = append([]AmbientLayer(nil), settings.AmbientLayers...)
+	a.ambient.stopOnBreak = settings.AmbientStopOnBreak
+	a.ambient.mu.Unlock()
+
+	a.schedule.mu.Lock()
+	a.schedule.hours = settings.WorkingHours
+	a.schedule.mu.Unlock()
+
+	a.overlay.mu.Lock()
+	a.overlay.enabled = settings.BreakOverlay
+	a.overlay.mu.Unlock()
+
+	a.window.mu.Lock()
+	a.window.startMinimized = settings.StartMinimized
+	a.window.mu.Unlock()
+
+	a.presentation.mu.Lock()
+	a.presentation.enabled = settings.PresentationMode
+	a.presentation.mu.Unlock()
+
+	a.focusLock.mu.Lock()
+	a.focusLock.settings = settings.FocusLock
+	a.focusLock.mu.Unlock()
+
+	a.shortcuts.mu.Lock()
+	a.shortcuts.bindings = withDefaultShortcuts(settings.Shortcuts)
+	a.shortcuts.mu.Unlock()
+}
+
+// currentSettings collects the settings from every subsystem
+func (a *App) currentSettings() Settings {
+	settings := Settings{Version: currentSettingsVersion}
+
+	a.timer.mu.RLock()
+	settings.WorkDuration = a.timer.workDuration
+	settings.ShortBreakDuration = a.timer.shortBreakDuration
+	settings.LongBreakDuration = a.timer.longBreakDuration
+	settings.LongBreakInterval = a.timer.longBreakInterval
+	a.timer.mu.RUnlock()
+
+	settings.Sounds = a.GetSoundSettings()
+	a.sounds.mu.RLock()
+	settings.AlarmOutputDevice = a.sounds.outputDevice
+	settings.GentleAlarm = a.sounds.gentleAlarm
+	settings.MuteAlarmsWhenMicActive = a.sounds.muteOnMic
+	a.sounds.mu.RUnlock()
+
+	a.ambient.mu.Lock()
+	settings.AmbientLayers = append([]AmbientLayer(nil), a.ambient.layers...)
+	settings.AmbientStopOnBreak = a.ambient.stopOnBreak
+	a.ambient.mu.Unlock()
+
+	settings.WorkingHours = a.GetWorkingHours()
+	settings.BreakOverlay = a.GetBreakOverlay()
+	settings.StartMinimized = a.GetStartMinimized()
+	settings.PresentationMode = a.GetPresentationMode()
+	a.focusLock.mu.Lock()
+	settings.FocusLock = a.focusLock.settings
+	a.focusLock.mu.Unlock()
+	settings.Shortcuts = a.GetShortcuts()
+	return settings
+}
+
+// persistSettings announces changed settings with a "settings-changed"
+// event and schedules them to be written to disk.
+// It must be called without any subsystem lock held.
+func (a *App) persistSettings() {
+	// Hold the store lock across collecting and scheduling so the save
+	// cannot land in a profile that is being switched away from
+	a.storeMu.RLock()
+	settings := a.currentSettings()
+	if a.store != nil {
+		a.store.scheduleSave(settings)
+	}
+	a.storeMu.RUnlock()
+
+	a.emit("settings-changed", settings.public())
+}
+
+// flushSettings writes any pending settings change immediately
+func (a *App) flushSettings() {
+	a.storeMu.RLock()
+	defer a.storeMu.RUnlock()
+	a.flushStore()
+}
+
+// flushStore writes any pending save of the active store (must be called with storeMu held)
+func (a *App) flushStore() {
+	if a.store == nil {
+		return
+	}
+	if err := a.store.flush(); err != nil {
+		runtime.LogErrorf(a.ctx, "%v", err)
+	}
+}
+
+// GetAllSettings returns every persisted setting
+func (a *App) GetAllSettings() Settings {
+	return a.currentSettings().public()
+}
+
+// public returns a copy of the settings that is safe to hand to the frontend
+func (s Settings) public() Settings {
+	s.FocusLock.PasscodeSalt = ""
+	s.FocusLock.PasscodeHash = ""
+	return s
+}
+
+// reportSettingsDefaults tells the frontend when stored preferences were lost or recovered
+func (a *App) reportSettingsDefaults() {
+	report := a.GetSettingsLoadReport()
+	if report == nil {
+		return
+	}
+	if report.RestoredFromBackup {
+		a.emit("settings-recovered", *report)
+		return
+	}
+	a.emit("settings-defaults-applied", *report)
+}
+
+// GetSettingsLoadReport returns problems found when settings were loaded, if any
+func (a *App) GetSettingsLoadReport() *SettingsLoadReport {
+	store := a.currentStore()
+	if store == nil {
+		return nil
+	}
+	return store.report
+}
@@ -0,0 +1,161 @@
+package main
+
+import (
+	"bytes"
+	"os"
+	"path/filepath"
+	"testing"
+)
+
+// writeFile creates a file for a test, failing the test if it cannot
+func writeFile(t *testing.T, path, contents string) {
+	t.Helper()
+	if err := os.WriteFile(path, []byte(contents), 0o644); err != nil {
+		t.Fatal(err)
+	}
+}
+
+func TestLoadNewerVersionKeepsFile(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "settings.json")
+	original := `{"version": 99, "workDuration": 50}`
+	writeFile(t, path, original)
+
+	store := &settingsStore{path: path}
+	settings := store.load()
+
+	if settings.WorkDuration != defaultSettings().WorkDuration {
+		t.Errorf("workDuration = %d, want the default", settings.WorkDuration)
+	}
+	if store.report == nil {
+		t.Fatal("expected a load report for a newer version")
+	}
+	if err := store.save(settings); err == nil {
+		t.Error("expected saving over a newer settings file to fail")
+	}
+
+	data, err := os.ReadFile(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if string(data) != original {
+		t.Errorf("newer settings file was overwritten: %s", data)
+	}
+}
+
+func TestLoadUnreadableFileKeepsFile(t *testing.T) {
+	// A directory in place of the file fails to read without being damaged
+	path := filepath.Join(t.TempDir(), "settings.json")
+	if err := os.Mkdir(path, 0o755); err != nil {
+		t.Fatal(err)
+	}
+
+	store := &settingsStore{path: path}
+	settings := store.load()
+
+	if store.report == nil || store.report.RestoredFromBackup {
+		t.Errorf("report = %+v, want defaults without a restored backup", store.report)
+	}
+	if err := store.save(settings); err == nil {
+		t.Error("expected saving over an unreadable settings file to fail")
+	}
+	if _, err := os.Stat(path + ".corrupt"); !os.IsNotExist(err) {
+		t.Error("unreadable settings file was moved aside as damaged")
+	}
+}
+
+func TestLoadOutOfRangeKeyFallsBackAlone(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "settings.json")
+	writeFile(t, path, `{"version": 1, "workDuration": 999, "shortBreakDuration": 7}`)
+
+	store := &settingsStore{path: path}
+	settings := store.load()
+
+	if settings.WorkDuration != defaultSettings().WorkDuration {
+		t.Errorf("workDuration = %d, want the default", settings.WorkDuration)
+	}
+	if settings.ShortBreakDuration != 7 {
+		t.Errorf("shortBreakDuration = %d, want 7", settings.ShortBreakDuration)
+	}
+	if store.report == nil || len(store.report.Substituted) != 1 || store.report.Substituted[0] != "workDuration" {
+		t.Errorf("report = %+v, want only workDuration substituted", store.report)
+	}
+}
+
+func TestLoadCorruptRestoresBackup(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "settings.json")
+	writeFile(t, path, `{"version": 1, "workDuration": `)
+	writeFile(t, path+".bak", `{"version": 1, "workDuration": 40}`)
+
+	store := &settingsStore{path: path}
+	settings := store.load()
+
+	if settings.WorkDuration != 40 {
+		t.Errorf("workDuration = %d, want 40 from the backup", settings.WorkDuration)
+	}
+	if store.report == nil || !store.report.RestoredFromBackup {
+		t.Errorf("report = %+v, want a restored backup", store.report)
+	}
+	if _, err := os.Stat(path + ".corrupt"); err != nil {
+		t.Errorf("damaged file was not kept: %v", err)
+	}
+	if _, err := os.Stat(path); err != nil {
+		t.Errorf("backup was not written back: %v", err)
+	}
+}
+
+func TestLoadCorruptWithoutBackupUsesDefaults(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "settings.json")
+	writeFile(t, path, `not json`)
+
+	store := &settingsStore{path: path}
+	settings := store.load()
+
+	if settings.WorkDuration != defaultSettings().WorkDuration {
+		t.Errorf("workDuration = %d, want the default", settings.WorkDuration)
+	}
+	if store.report == nil || store.report.RestoredFromBackup {
+		t.Errorf("report = %+v, want defaults without a restored backup", store.report)
+	}
+	if store.report != nil && store.report.CorruptCopy != path+".corrupt" {
+		t.Errorf("corrupt copy = %q, want %q", store.report.CorruptCopy, path+".corrupt")
+	}
+}
+
+func TestWriteSkipsUnchangedSettings(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "settings.json")
+	store := &settingsStore{path: path}
+
+	settings := defaultSettings()
+	if err := store.save(settings); err != nil {
+		t.Fatal(err)
+	}
+	first, err := os.ReadFile(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if err := os.Remove(path); err != nil {
+		t.Fatal(err)
+	}
+
+	if err := store.save(settings); err != nil {
+		t.Fatal(err)
+	}
+	if _, err := os.Stat(path); !os.IsNotExist(err) {
+		t.Error("unchanged settings were written again")
+	}
+	if _, err := os.Stat(path + ".bak"); !os.IsNotExist(err) {
+		t.Error("unchanged settings created a backup")
+	}
+
+	settings.WorkDuration = 30
+	if err := store.save(settings); err != nil {
+		t.Fatal(err)
+	}
+	backup, err := os.ReadFile(path + ".bak")
+	if err != nil {
+		t.Fatalf("changed settings did not keep a backup: %v", err)
+	}
+	if !bytes.Equal(backup, first) {
+		t.Errorf("backup = %s, want the previous settings", backup)
+	}
+}
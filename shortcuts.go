@@ -0,0 +1,150 @@
+package main
+
+import (
+	"fmt"
+	"sort"
+	"strings"
+	"sync"
+
+	"github.com/wailsapp/wails/v2/pkg/menu/keys"
+	"github.com/wailsapp/wails/v2/pkg/runtime"
+)
+
+// defaultShortcuts maps every menu action to its accelerator, in the
+// format keys.Parse accepts. An empty accelerator leaves the action
+// without a shortcut.
+var defaultShortcuts = map[string]string{
+	"start":            "CmdOrCtrl+S",
+	"pause":            "CmdOrCtrl+P",
+	"stop":             "CmdOrCtrl+.",
+	"skipBreak":        "CmdOrCtrl+K",
+	"toggleAmbient":    "CmdOrCtrl+Shift+A",
+	"quit":             "CmdOrCtrl+Q",
+	"toggleFullscreen": "F11",
+	"showBreakOverlay": "CmdOrCtrl+Shift+B",
+	"presentationMode": "CmdOrCtrl+Shift+P",
+}
+
+// ShortcutSettings holds the accelerator of every menu action
+type ShortcutSettings struct {
+	mu       sync.RWMutex
+	bindings map[string]string
+}
+
+// NewShortcutSettings creates shortcuts with the default accelerators
+func NewShortcutSettings() *ShortcutSettings {
+	return &ShortcutSettings{bindings: withDefaultShortcuts(nil)}
+}
+
+// withDefaultShortcuts returns the given bindings with every missing action
+// set to its default
+func withDefaultShortcuts(bindings map[string]string) map[string]string {
+	merged := make(map[string]string, len(defaultShortcuts))
+	for action, accelerator := range defaultShortcuts {
+		merged[action] = accelerator
+	}
+	for action, accelerator := range bindings {
+		merged[action] = accelerator
+	}
+	return merged
+}
+
+// acceleratorKey renders an accelerator in one canonical form, so that
+// "Shift+CmdOrCtrl+a" and "cmdorctrl+shift+A" count as the same shortcut
+func acceleratorKey(accelerator *keys.Accelerator) string {
+	parts := make([]string, 0, len(accelerator.Modifiers)+1)
+	for _, modifier := range accelerator.Modifiers {
+		parts = append(parts, strings.ToLower(string(modifier)))
+	}
+	sort.Strings(parts)
+	return strings.Join(append(parts, strings.ToLower(accelerator.Key)), "+")
+}
+
+// validateShortcuts checks that every action is known, every accelerator
+// can be parsed and no accelerator is used twice
+func validateShortcuts(bindings map[string]string) ValidationErrors {
+	actions := make([]string, 0, len(bindings))
+	for action := range bindings {
+		actions = append(actions, action)
+	}
+	sort.Strings(actions)
+
+	var errs ValidationErrors
+	usedBy := make(map[string]string)
+	for _, action := range actions {
+		if _, known := defaultShortcuts[action]; !known {
+			errs = append(errs, FieldError{Field: action, Message: "unknown action"})
+			continue
+		}
+		if bindings[action] == "" {
+			continue
+		}
+
+		accelerator, err := keys.Parse(bindings[action])
+		if err != nil {
+			errs = append(errs, FieldError{Field: action, Message: fmt.Sprintf("invalid shortcut %q: %v", bindings[action], err)})
+			continue
+		}
+		key := acceleratorKey(accelerator)
+		if other, taken := usedBy[key]; taken {
+			errs = append(errs, FieldError{Field: action, Message: fmt.Sprintf("%q is already used by %s", bindings[action], other)})
+			continue
+		}
+		usedBy[key] = action
+	}
+	return errs
+}
+
+// accelerator returns the parsed shortcut of a menu action, or nil if it has none
+func (a *App) accelerator(action string) *keys.Accelerator {
+	a.shortcuts.mu.RLock()
+	value := a.shortcuts.bindings[action]
+	a.shortcuts.mu.RUnlock()
+
+	if value == "" {
+		return nil
+	}
+	accelerator, err := keys.Parse(value)
+	if err != nil {
+		return nil
+	}
+	return accelerator
+}
+
+// GetShortcuts returns the accelerator of every menu action
+func (a *App) GetShortcuts() map[string]string {
+	a.shortcuts.mu.RLock()
+	defer a.shortcuts.mu.RUnlock()
+	return withDefaultShortcuts(a.shortcuts.bindings)
+}
+
+// SetShortcut changes the accelerator of one menu action. An empty
+// accelerator removes the shortcut. The menu is rebuilt right away.
+func (a *App) SetShortcut(action, accelerator string) (map[string]string, error) {
+	if err := a.checkWritable(); err != nil {
+		return a.GetShortcuts(), err
+	}
+
+	bindings := a.GetShortcuts()
+	bindings[action] = accelerator
+	if errs := validateShortcuts(bindings); len(errs) > 0 {
+		return a.GetShortcuts(), errs
+	}
+
+	a.shortcuts.mu.Lock()
+	a.shortcuts.bindings = bindings
+	a.shortcuts.mu.Unlock()
+
+	a.persistSettings()
+	a.refreshMenu()
+	return a.GetShortcuts(), nil
+}
+
+// refreshMenu rebuilds the application menu so it shows the current
+// shortcuts and presentation mode
+func (a *App) refreshMenu() {
+	if a.ctx == nil {
+		return
+	}
+	runtime.MenuSetApplicationMenu(a.ctx, a.applicationMenu())
+}
@@ -0,0 +1,110 @@
+package main
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"os"
+	"os/signal"
+	"path/filepath"
+	"syscall"
+
+	"github.com/wailsapp/wails/v2/pkg/runtime"
+)
+
+// timerSnapshot is the timer state saved on shutdown and restored on the next launch
+type timerSnapshot struct {
+	State              TimerState `json:"state"`
+	RemainingTime      int        `json:"remainingTime"`
+	CurrentCycle       int        `json:"currentCycle"`
+	CompletedPomodoros int        `json:"completedPomodoros"`
+}
+
+// timerSnapshotPath returns where the timer state is kept between launches
+func (a *App) timerSnapshotPath() string {
+	return filepath.Join(a.configDir, "timer.json")
+}
+
+// quitOnSignal turns SIGINT and SIGTERM, as sent by the OS at logout or
+// shutdown, into a normal quit so OnShutdown still saves the session.
+// The focus lock does not hold back a quit the OS asked for.
+func (a *App) quitOnSignal() {
+	signals := make(chan os.Signal, 1)
+	signal.Notify(signals, os.Interrupt, syscall.SIGTERM)
+	<-signals
+	signal.Stop(signals)
+
+	a.quitting.Store(true)
+	runtime.Quit(a.ctx)
+}
+
+// shutdown is called when the app is quitting, from the menu, the window
+// or the OS. It pauses a running session, saves it so the next launch can
+// resume it, and flushes any pending settings change.
+func (a *App) shutdown(ctx context.Context) {
+	a.timer.mu.Lock()
+	if a.timer.state != StateIdle {
+		a.timer.state = StatePaused
+	}
+	a.timer.stopTicking()
+	snapshot := timerSnapshot(a.getStatus())
+	a.timer.mu.Unlock()
+
+	a.stopAmbient()
+	a.flushSettings()
+
+	if a.configDir == "" {
+		return
+	}
+	if err := a.saveTimerSnapshot(snapshot); err != nil {
+		runtime.LogErrorf(ctx, "%v", err)
+	}
+}
+
+// saveTimerSnapshot writes the timer state, or removes a stale one when idle
+func (a *App) saveTimerSnapshot(snapshot timerSnapshot) error {
+	path := a.timerSnapshotPath()
+	if snapshot.State == StateIdle {
+		if err := os.Remove(path); err != nil && !os.IsNotExist(err) {
+			return fmt.Errorf("failed to remove timer state: %w", err)
+		}
+		return nil
+	}
+
+	data, err := json.MarshalIndent(snapshot, "", "  ")
+	if err != nil {
+		return fmt.Errorf("failed to encode timer state: %w", err)
+	}
+	// Nothing else may have created the directory yet on a first run
+	if err := os.MkdirAll(a.configDir, 0o755); err != nil {
+		return fmt.Errorf("failed to create settings directory: %w", err)
+	}
+	if err := os.WriteFile(path, data, 0o644); err != nil {
+		return fmt.Errorf("failed to write timer state: %w", err)
+	}
+	return nil
+}
+
+// restoreTimer resumes the session saved by the last shutdown in a paused state
+func (a *App) restoreTimer() {
+	if a.configDir == "" {
+		return
+	}
+	data, err := os.ReadFile(a.timerSnapshotPath())
+	if err != nil {
+		return
+	}
+	os.Remove(a.timerSnapshotPath())
+
+	var snapshot timerSnapshot
+	if err := json.Unmarshal(data, &snapshot); err != nil || snapshot.State != StatePaused || snapshot.RemainingTime <= 0 {
+		return
+	}
+
+	a.timer.mu.Lock()
+	a.timer.state = StatePaused
+	a.timer.remainingTime = snapshot.RemainingTime
+	a.timer.currentCycle = snapshot.CurrentCycle
+	a.timer.completedPomodoros = snapshot.CompletedPomodoros
+	a.timer.mu.Unlock()
+}
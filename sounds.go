@@ -0,0 +1,224 @@
+package main
+
+import (
+	"fmt"
+	"sync"
+)
+
+// alarmSounds lists the completion sounds the frontend knows how to play
+var alarmSounds = []string{"bell", "chime", "digital", "none"}
+
+// SessionSound configures the sound played when a session of a given type ends
+type SessionSound struct {
+	Sound  string  `json:"sound"`
+	Volume float64 `json:"volume"` // 0.0 - 1.0
+}
+
+// SessionCompleteEvent is emitted to the frontend when a session ends
+type SessionCompleteEvent struct {
+	Finished TimerState   `json:"finished"`
+	Next     TimerState   `json:"next"` // idle when working hours stopped the timer
+	Sound    SessionSound `json:"sound"`
+	Device   string       `json:"device"` // empty for the system default output
+	Gentle   bool         `json:"gentle"`   // ramp up from quiet until acknowledged
+	Silenced bool         `json:"silenced"` // microphone in use, show without sound
+}
+
+// SoundSettings holds the completion sound for each session type
+type SoundSettings struct {
+	mu           sync.RWMutex
+	sessions     map[TimerState]SessionSound
+	outputDevice string
+	gentleAlarm  bool
+	muteOnMic    bool
+}
+
+// NewSoundSettings creates sound settings with a sound for every session type
+func NewSoundSettings() *SoundSettings {
+	return &SoundSettings{
+		sessions: map[TimerState]SessionSound{
+			StateWorking:   {Sound: "bell", Volume: 0.8},
+			StateBreak:     {Sound: "chime", Volume: 0.6},
+			StateLongBreak: {Sound: "chime", Volume: 0.6},
+		},
+		muteOnMic: true,
+	}
+}
+
+// soundFor returns the completion sound for a session type
+func (s *SoundSettings) soundFor(state TimerState) SessionSound {
+	s.mu.RLock()
+	defer s.mu.RUnlock()
+	return s.sessions[state]
+}
+
+// device returns the selected alarm output device
+func (s *SoundSettings) device() string {
+	s.mu.RLock()
+	defer s.mu.RUnlock()
+	return s.outputDevice
+}
+
+// gentle reports whether alarms should ramp up gradually
+func (s *SoundSettings) gentle() bool {
+	s.mu.RLock()
+	defer s.mu.RUnlock()
+	return s.gentleAlarm
+}
+
+// silenced reports whether alarms should be silent because the microphone is in use
+func (s *SoundSettings) silenced() bool {
+	s.mu.RLock()
+	muteOnMic := s.muteOnMic
+	s.mu.RUnlock()
+
+	if !muteOnMic {
+		return false
+	}
+	inUse, _ := microphoneInUse()
+	return inUse
+}
+
+// validateSessionSound checks the session type, sound name and volume
+func validateSessionSound(state TimerState, sound SessionSound) ValidationErrors {
+	var errs ValidationErrors
+	if state != StateWorking && state != StateBreak && state != StateLongBreak {
+		errs = append(errs, FieldError{Field: "sessionType", Message: fmt.Sprintf("unknown session type %q", state)})
+	}
+
+	known := false
+	for _, name := range alarmSounds {
+		if sound.Sound == name {
+			known = true
+			break
+		}
+	}
+	if !known {
+		errs = append(errs, FieldError{Field: "sound", Message: fmt.Sprintf("unknown sound %q", sound.Sound)})
+	}
+	if sound.Volume < 0 || sound.Volume > 1 {
+		errs = append(errs, FieldError{Field: "volume", Message: "must be between 0.0 and 1.0"})
+	}
+	return errs
+}
+
+// GetSoundSettings returns the completion sound for every session type
+func (a *App) GetSoundSettings() map[TimerState]SessionSound {
+	a.sounds.mu.RLock()
+	defer a.sounds.mu.RUnlock()
+
+	sessions := make(map[TimerState]SessionSound, len(a.sounds.sessions))
+	for state, sound := range a.sounds.sessions {
+		sessions[state] = sound
+	}
+	return sessions
+}
+
+// UpdateSessionSound sets the completion sound and volume for one session type
+func (a *App) UpdateSessionSound(sessionType TimerState, sound SessionSound) error {
+	if err := a.checkWritable(); err != nil {
+		return err
+	}
+
+	if errs := validateSessionSound(sessionType, sound); len(errs) > 0 {
+		return errs
+	}
+
+	a.sounds.mu.Lock()
+	a.sounds.sessions[sessionType] = sound
+	a.sounds.mu.Unlock()
+
+	a.persistSettings()
+	return nil
+}
+
+// GetAlarmOutputDevice returns the output device alarms are played on
+func (a *App) GetAlarmOutputDevice() string {
+	return a.sounds.device()
+}
+
+// SetAlarmOutputDevice selects the output device for alarms.
+// Device ids come from the frontend's device enumeration; an empty id
+// means the system default. If the device is missing when an alarm
+// plays, the frontend falls back to the default output.
+func (a *App) SetAlarmOutputDevice(deviceID string) error {
+	if err := a.checkWritable(); err != nil {
+		return err
+	}
+
+	a.sounds.mu.Lock()
+	a.sounds.outputDevice = deviceID
+	a.sounds.mu.Unlock()
+
+	a.persistSettings()
+	return nil
+}
+
+// GetGentleAlarm reports whether gentle alarm mode is enabled
+func (a *App) GetGentleAlarm() bool {
+	return a.sounds.gentle()
+}
+
+// SetGentleAlarm enables or disables gentle alarm mode, where the
+// completion sound starts quiet and repeats louder until the user
+// interacts with the app
+func (a *App) SetGentleAlarm(enabled bool) error {
+	if err := a.checkWritable(); err != nil {
+		return err
+	}
+
+	a.sounds.mu.Lock()
+	a.sounds.gentleAlarm = enabled
+	a.sounds.mu.Unlock()
+
+	a.persistSettings()
+	return nil
+}
+
+// MicrophoneStatus reports microphone usage and the related alarm setting
+type MicrophoneStatus struct {
+	MuteAlarms bool `json:"muteAlarms"`
+	InUse      bool `json:"inUse"`
+	Supported  bool `json:"supported"` // false if usage cannot be detected on this system
+}
+
+// GetMicrophoneStatus returns whether the microphone is in use and whether alarms are muted then
+func (a *App) GetMicrophoneStatus() MicrophoneStatus {
+	a.sounds.mu.RLock()
+	muteOnMic := a.sounds.muteOnMic
+	a.sounds.mu.RUnlock()
+
+	inUse, supported := microphoneInUse()
+	return MicrophoneStatus{
+		MuteAlarms: muteOnMic,
+		InUse:      inUse,
+		Supported:  supported,
+	}
+}
+
+// SetMuteAlarmsWhenMicActive configures whether alarms stay silent while
+// the microphone is in use, e.g. during a call or recording
+func (a *App) SetMuteAlarmsWhenMicActive(enabled bool) error {
+	if err := a.checkWritable(); err != nil {
+		return err
+	}
+
+	a.sounds.mu.Lock()
+	a.sounds.muteOnMic = enabled
+	a.sounds.mu.Unlock()
+
+	a.persistSettings()
+	return nil
+}
+
+// announceSessionComplete tells the frontend which sound to play for a finished session
+func (a *App) announceSessionComplete(finished, next TimerState) {
+	a.emit("session-complete", SessionCompleteEvent{
+		Finished: finished,
+		Next:     next,
+		Sound:    a.sounds.soundFor(finished),
+		Device:   a.sounds.device(),
+		Gentle:   a.sounds.gentle(),
+		Silenced: a.sounds.silenced(),
+	})
+}
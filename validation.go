@@ -0,0 +1,106 @@
+package main
+
+import (
+	"errors"
+	"fmt"
+	"strings"
+)
+
+// Duration limits in minutes for every session type
+const (
+	minSessionMinutes = 1
+	maxSessionMinutes = 240
+	maxLongBreakEvery = 12
+)
+
+// FieldError describes why a single setting was rejected
+type FieldError struct {
+	Field   string `json:"field"`
+	Message string `json:"message"`
+}
+
+// ValidationErrors collects every invalid field of a settings update
+type ValidationErrors []FieldError
+
+// Error lists each invalid field with its reason
+func (e ValidationErrors) Error() string {
+	parts := make([]string, len(e))
+	for i, fieldErr := range e {
+		parts[i] = fmt.Sprintf("%s: %s", fieldErr.Field, fieldErr.Message)
+	}
+	return strings.Join(parts, "; ")
+}
+
+// BindingError is what the frontend receives when a bound method fails.
+// Fields is set when the failure was a settings validation error.
+type BindingError struct {
+	Message string       `json:"message"`
+	Fields  []FieldError `json:"fields,omitempty"`
+}
+
+// formatBindingError passes validation errors to the frontend field by
+// field instead of as a single message
+func formatBindingError(err error) any {
+	var validation ValidationErrors
+	if errors.As(err, &validation) {
+		return BindingError{Message: err.Error(), Fields: validation}
+	}
+	return err.Error()
+}
+
+// has reports whether the given field was rejected
+func (e ValidationErrors) has(field string) bool {
+	for _, fieldErr := range e {
+		if fieldErr.Field == field {
+			return true
+		}
+	}
+	return false
+}
+
+// validateDuration checks a session length in minutes
+func validateDuration(errs ValidationErrors, field string, minutes int) ValidationErrors {
+	if minutes < minSessionMinutes || minutes > maxSessionMinutes {
+		errs = append(errs, FieldError{
+			Field:   field,
+			Message: fmt.Sprintf("must be between %d and %d minutes", minSessionMinutes, maxSessionMinutes),
+		})
+	}
+	return errs
+}
+
+// validateTimerSettings checks the durations and long break interval
+func validateTimerSettings(workDuration, shortBreak, longBreak, longBreakInterval int) ValidationErrors {
+	var errs ValidationErrors
+	errs = validateDuration(errs, "workDuration", workDuration)
+	errs = validateDuration(errs, "shortBreakDuration", shortBreak)
+	errs = validateDuration(errs, "longBreakDuration", longBreak)
+	if longBreakInterval < 1 || longBreakInterval > maxLongBreakEvery {
+		errs = append(errs, FieldError{
+			Field:   "longBreakInterval",
+			Message: fmt.Sprintf("must be between 1 and %d pomodoros", maxLongBreakEvery),
+		})
+	}
+	return errs
+}
+
+// validateSettings checks every persisted setting
+func validateSettings(settings Settings) ValidationErrors {
+	errs := validateTimerSettings(settings.WorkDuration, settings.ShortBreakDuration, settings.LongBreakDuration, settings.LongBreakInterval)
+
+	for state, sound := range settings.Sounds {
+		if soundErrs := validateSessionSound(state, sound); len(soundErrs) > 0 {
+			errs = append(errs, FieldError{Field: "sounds", Message: fmt.Sprintf("%s: %v", state, soundErrs)})
+		}
+	}
+	if mixErrs := validateAmbientMix(settings.AmbientLayers); len(mixErrs) > 0 {
+		errs = append(errs, FieldError{Field: "ambientLayers", Message: mixErrs.Error()})
+	}
+	if hoursErrs := validateWorkingHours(settings.WorkingHours); len(hoursErrs) > 0 {
+		errs = append(errs, FieldError{Field: "workingHours", Message: hoursErrs.Error()})
+	}
+	if shortcutErrs := validateShortcuts(settings.Shortcuts); len(shortcutErrs) > 0 {
+		errs = append(errs, FieldError{Field: "shortcuts", Message: shortcutErrs.Error()})
+	}
+	return errs
+}
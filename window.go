@@ -0,0 +1,42 @@
+package main
+
+import "sync"
+
+// WindowPreferences holds how the main window behaves at launch
+type WindowPreferences struct {
+	mu             sync.RWMutex
+	startMinimized bool
+}
+
+// NewWindowPreferences creates preferences that open the window normally
+func NewWindowPreferences() *WindowPreferences {
+	return &WindowPreferences{}
+}
+
+// GetStartMinimized reports whether the app launches minimized
+func (a *App) GetStartMinimized() bool {
+	a.window.mu.RLock()
+	defer a.window.mu.RUnlock()
+	return a.window.startMinimized
+}
+
+// SetStartMinimized configures whether the app launches minimized.
+// Passing --minimized on the command line has the same effect for one launch.
+func (a *App) SetStartMinimized(enabled bool) error {
+	if err := a.checkWritable(); err != nil {
+		return err
+	}
+
+	a.window.mu.Lock()
+	a.window.startMinimized = enabled
+	a.window.mu.Unlock()
+
+	a.persistSettings()
+	return nil
+}
+
+// GetLaunchedMinimized reports whether this launch started in the background,
+// either from --minimized or from the start-minimized setting
+func (a *App) GetLaunchedMinimized() bool {
+	return a.launchMinimized
+}